@@ -0,0 +1,690 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec, vec::Vec};
+use alloc::boxed::Box;
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{account::AccountHash, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint, EntryPointAccess, EntryPoints, EntryPointType, Key, NamedKeys, Parameter, runtime_args, RuntimeArgs, system::CallStackElement, U512};
+
+use casper_private_auction_core::{accounts, constructors, entry_points, functions, handlers, keys, utils};
+use casper_private_auction_core::keys::{read_named_key_value, write_named_key_value};
+use casper_private_auction_core::agents::BiddingAgents;
+use casper_private_auction_core::auction::Auction;
+use casper_private_auction_core::constructors::MarketplaceSettings;
+use casper_private_auction_core::credit::{CreditLedger, CreditLine};
+use casper_private_auction_core::data::AuctionData;
+use casper_private_auction_core::error::AuctionError;
+use casper_private_auction_core::info::AuctionInfo;
+use casper_private_auction_core::registry::{AuctionRecord, Registry};
+use casper_private_auction_core::status::{AuctionStatus, ContractType};
+
+// Package of the factory, as the calling stored contract
+fn factory_package_hash() -> ContractPackageHash {
+    match runtime::get_call_stack().last() {
+        Some(CallStackElement::StoredContract { contract_package_hash, .. }) => *contract_package_hash,
+        _ => runtime::revert(AuctionError::InvalidCaller),
+    }
+}
+
+// Named key the factory keeps the access token to a created package under
+fn access_token_name(package_hash: ContractPackageHash) -> String {
+    format!("{}_access_token", package_hash.to_formatted_string())
+}
+
+/**
+ * Create a new contract package holding one version with the given entry points and named keys,
+ * register it and hand back its package hash. The factory keeps the access token to the package
+ * in its named keys, so it can add versions to it later.
+ */
+fn create(contract_type: ContractType, entry_points: EntryPoints, mut named_keys: NamedKeys, token: Option<(ContractPackageHash, String)>) {
+    named_keys.insert(String::from(keys::FACTORY), storage::new_uref(Some(factory_package_hash())).into());
+    let (package_hash, access_token) = storage::create_contract_package_at_hash();
+    runtime::put_key(&access_token_name(package_hash), access_token.into());
+    let (contract_hash, _) = storage::add_contract_version(package_hash, entry_points, named_keys);
+    let seller = Key::Account(runtime::get_caller());
+
+    // Create the purse and dictionaries in the contract's context, never in the factory's
+    runtime::call_contract::<()>(contract_hash, functions::INIT, runtime_args! {});
+    if let Some((token_package_hash, token_id)) = &token {
+        // Transfer the NFT ownership to the auction
+        runtime::call_versioned_contract::<()>(
+            *token_package_hash,
+            None,
+            "transfer",
+            runtime_args! {
+                "sender" => seller,
+                "recipient" => Key::Hash(package_hash.value()),
+                "token_ids" => vec![token_id.clone()],
+            },
+        );
+    }
+
+    Registry::at().register(AuctionRecord {
+        auction: package_hash,
+        contract_type,
+        seller,
+        token,
+        status: AuctionStatus::Live,
+    });
+    runtime::ret(CLValue::from_t(package_hash).unwrap_or_revert());
+}
+
+// The token put up for auction, from the creation arguments
+fn auction_token() -> Option<(ContractPackageHash, String)> {
+    let token_package_hash = runtime::get_named_arg::<Key>(keys::TOKEN_PACKAGE_HASH)
+        .into_hash()
+        .unwrap_or_revert_with(AuctionError::MissingTokenPackageHash);
+    Some((ContractPackageHash::new(token_package_hash), runtime::get_named_arg::<String>(keys::TOKEN_ID)))
+}
+
+// Marketplace settings of the auctions the factory creates, changed by the admin with `set_marketplace_settings`
+fn marketplace_settings() -> MarketplaceSettings {
+    (
+        read_named_key_value(keys::MARKETPLACE_ACCOUNT),
+        read_named_key_value(keys::MAX_MARKETPLACE_COMMISSION),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn create_english_auction() {
+    let named_keys = constructors::create_english_auction_named_keys(marketplace_settings());
+    create(ContractType::EnglishAuction, entry_points::english(), named_keys, auction_token());
+}
+
+#[no_mangle]
+pub extern "C" fn create_dutch_auction() {
+    let named_keys = constructors::create_dutch_auction_named_keys(marketplace_settings());
+    create(ContractType::DutchAuction, entry_points::dutch(), named_keys, auction_token());
+}
+
+#[no_mangle]
+pub extern "C" fn create_swap() {
+    let named_keys = constructors::create_swap_named_keys(marketplace_settings());
+    create(ContractType::Swap, entry_points::swap(), named_keys, auction_token());
+}
+
+#[no_mangle]
+pub extern "C" fn create_gift() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let named_keys = constructors::create_gift_named_keys(contract_name);
+    create(ContractType::Gift, entry_points::gift(), named_keys, None);
+}
+
+/**
+ * Add a version running the current factory wasm to a created contract, its named keys carry over.
+ * The factory is upgraded first, the admin of the contract then calls its `migrate`.
+ */
+#[no_mangle]
+pub extern "C" fn upgrade_auction() {
+    // Only admin is allowed to call this
+    Auction::check_admin();
+    let auction = auction_arg();
+    let record = Registry::at()
+        .record_of(auction)
+        .unwrap_or_revert_with(AuctionError::AuctionNotRegistered);
+    let entry_points = match record.contract_type {
+        ContractType::EnglishAuction => entry_points::english(),
+        ContractType::DutchAuction => entry_points::dutch(),
+        ContractType::Swap => entry_points::swap(),
+        ContractType::Gift => entry_points::gift(),
+    };
+    let (contract_hash, _) = storage::add_contract_version(auction, entry_points, NamedKeys::new());
+    runtime::ret(CLValue::from_t(contract_hash).unwrap_or_revert());
+}
+
+// Package of the contract calling into the factory
+fn calling_auction() -> ContractPackageHash {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.iter().rev().nth(1) {
+        Some(CallStackElement::StoredContract { contract_package_hash, .. }) => *contract_package_hash,
+        _ => runtime::revert(AuctionError::InvalidCaller),
+    }
+}
+
+// Only the contracts created by the factory can hold credit or report their status
+fn registered_auction() -> ContractPackageHash {
+    let auction = calling_auction();
+    if !Registry::at().is_registered(auction) {
+        runtime::revert(AuctionError::AuctionNotRegistered)
+    }
+    auction
+}
+
+fn auction_arg() -> ContractPackageHash {
+    ContractPackageHash::new(
+        runtime::get_named_arg::<Key>(keys::AUCTION)
+            .into_hash()
+            .unwrap_or_revert_with(AuctionError::AuctionNotRegistered),
+    )
+}
+
+// Credit is kept per bidder, an account or a contract bidding through an agent
+fn bidder_arg(name: &str) -> Key {
+    let bidder = runtime::get_named_arg::<Key>(name);
+    BiddingAgents::check_bidder(&bidder);
+    bidder
+}
+
+fn account_arg(name: &str) -> AccountHash {
+    runtime::get_named_arg::<Key>(name)
+        .into_account()
+        .unwrap_or_revert_with(AuctionError::KeyNotAccount)
+}
+
+// Created contracts report every status change, only for themselves
+#[no_mangle]
+pub extern "C" fn update_status() {
+    let auction = registered_auction();
+    let status = runtime::get_named_arg::<AuctionStatus>(keys::STATUS);
+    Registry::at().update_status(auction, status);
+}
+
+/**
+ * Pull the current status of a created contract into the registry, to repair records of contracts that
+ * changed status without reporting it (created by an earlier factory version and not upgraded yet).
+ * Anyone can call it since the status is read from the contract itself. Gift contracts have no status
+ * and stay listed as live.
+ */
+#[no_mangle]
+pub extern "C" fn sync_status() {
+    let auction = auction_arg();
+    let registry = Registry::at();
+    let record = registry
+        .record_of(auction)
+        .unwrap_or_revert_with(AuctionError::AuctionNotRegistered);
+    if record.contract_type == ContractType::Gift {
+        return;
+    }
+    let info: AuctionInfo =
+        runtime::call_versioned_contract(auction, None, functions::GET_AUCTION_INFO, runtime_args! {});
+    registry.update_status(auction, info.status);
+}
+
+#[no_mangle]
+pub extern "C" fn reserve_credit() {
+    let auction = registered_auction();
+    let amount = runtime::get_named_arg::<U512>(keys::AMOUNT);
+    CreditLedger::at().reserve(auction, bidder_arg(keys::BIDDER), amount);
+}
+
+#[no_mangle]
+pub extern "C" fn release_credit() {
+    let auction = registered_auction();
+    CreditLedger::at().release(auction, bidder_arg(keys::BIDDER));
+}
+
+#[no_mangle]
+pub extern "C" fn settle_credit() {
+    let auction = registered_auction();
+    CreditLedger::at().settle(auction, bidder_arg(keys::BIDDER));
+}
+
+#[no_mangle]
+pub extern "C" fn set_credit_limit() {
+    // Only admin is allowed to call this
+    Auction::check_admin();
+    let limit = runtime::get_named_arg::<Option<U512>>(keys::CREDIT_LIMIT);
+    CreditLedger::at().set_limit(bidder_arg(keys::ACCOUNT), limit);
+}
+
+#[no_mangle]
+pub extern "C" fn repay_credit() {
+    // Only admin is allowed to call this
+    Auction::check_admin();
+    let amount = runtime::get_named_arg::<U512>(keys::AMOUNT);
+    CreditLedger::at().repay(bidder_arg(keys::ACCOUNT), amount);
+}
+
+#[no_mangle]
+pub extern "C" fn get_credit() {
+    let line = CreditLedger::at().line(bidder_arg(keys::ACCOUNT));
+    runtime::ret(CLValue::from_t(line).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_marketplace_settings() {
+    // Only admin is allowed to call this
+    Auction::check_admin();
+    let max_commission = runtime::get_named_arg::<u32>(keys::MAX_MARKETPLACE_COMMISSION);
+    if max_commission == 0 || max_commission > utils::BASIS_POINTS {
+        runtime::revert(AuctionError::InvalidMarketplaceCommission)
+    }
+    write_named_key_value(keys::MARKETPLACE_ACCOUNT, account_arg(keys::MARKETPLACE_ACCOUNT));
+    write_named_key_value(keys::MAX_MARKETPLACE_COMMISSION, max_commission);
+}
+
+#[no_mangle]
+pub extern "C" fn get_marketplace_settings() {
+    runtime::ret(CLValue::from_t(marketplace_settings()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auction_count() {
+    runtime::ret(CLValue::from_t(Registry::at().len()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions() {
+    let offset = runtime::get_named_arg::<u32>(keys::OFFSET);
+    let limit = runtime::get_named_arg::<u32>(keys::LIMIT);
+    runtime::ret(CLValue::from_t(Registry::at().all(offset, limit)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_seller() {
+    let seller = runtime::get_named_arg::<Key>(keys::SELLER);
+    let offset = runtime::get_named_arg::<u32>(keys::OFFSET);
+    let limit = runtime::get_named_arg::<u32>(keys::LIMIT);
+    runtime::ret(CLValue::from_t(Registry::at().by_seller(&seller, offset, limit)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_status() {
+    let status = runtime::get_named_arg::<AuctionStatus>(keys::STATUS);
+    let offset = runtime::get_named_arg::<u32>(keys::OFFSET);
+    let limit = runtime::get_named_arg::<u32>(keys::LIMIT);
+    runtime::ret(CLValue::from_t(Registry::at().by_status(status, offset, limit)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_token() {
+    let (token_package_hash, token_id) = auction_token().unwrap_or_revert();
+    let offset = runtime::get_named_arg::<u32>(keys::OFFSET);
+    let limit = runtime::get_named_arg::<u32>(keys::LIMIT);
+    runtime::ret(
+        CLValue::from_t(Registry::at().by_token(token_package_hash, &token_id, offset, limit)).unwrap_or_revert(),
+    );
+}
+
+// Entry points of the created contracts. They share this wasm, so entry points with the same
+// name in several formats dispatch on the contract type.
+
+#[no_mangle]
+pub extern "C" fn bid() {
+    match AuctionData::contract_type() {
+        ContractType::EnglishAuction => handlers::english::bid(),
+        ContractType::DutchAuction => handlers::dutch::bid(),
+        _ => handlers::swap::bid(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn synthetic_bid() {
+    match AuctionData::contract_type() {
+        ContractType::EnglishAuction => handlers::english::synthetic_bid(),
+        ContractType::DutchAuction => handlers::dutch::synthetic_bid(),
+        _ => handlers::swap::synthetic_bid(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_synthetic_bid() {
+    match AuctionData::contract_type() {
+        ContractType::EnglishAuction => handlers::english::cancel_synthetic_bid(),
+        ContractType::DutchAuction => handlers::dutch::cancel_synthetic_bid(),
+        _ => handlers::swap::cancel_synthetic_bid(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_auction() {
+    match AuctionData::contract_type() {
+        ContractType::EnglishAuction => handlers::english::cancel_auction(),
+        ContractType::DutchAuction => handlers::dutch::cancel_auction(),
+        _ => handlers::swap::cancel_auction(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    match AuctionData::contract_type() {
+        ContractType::Gift => handlers::gift::pause(),
+        _ => handlers::auction::pause(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    match AuctionData::contract_type() {
+        ContractType::Gift => handlers::gift::unpause(),
+        _ => handlers::auction::unpause(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+    match AuctionData::contract_type() {
+        ContractType::Gift => handlers::gift::init(),
+        _ => handlers::auction::init(),
+    }
+}
+
+casper_private_auction_core::export_handlers!(
+    english: proxy_bid,
+    cancel_bid,
+    cancel_with_penalty,
+    force_cancel,
+    finalize,
+    get_min_next_bid
+);
+casper_private_auction_core::export_handlers!(
+    auction: approve,
+    reject,
+    fund_synthetic,
+    refresh_commissions,
+    resume_settlement,
+    redirect_payout,
+    update_gating_list,
+    update_bidding_agents,
+    get_bid,
+    get_fees,
+    get_auction_info,
+    migrate
+);
+casper_private_auction_core::export_handlers!(gift: claim, cancel, gift, get_gift_count, get_gifts);
+
+// Arguments shared by every auction format
+fn auction_parameters() -> Vec<Parameter> {
+    vec![
+        Parameter::new(keys::BENEFICIARY_ACCOUNT, CLType::Key),
+        Parameter::new(keys::BENEFICIARIES, CLType::Option(Box::new(CLType::Map {
+            key: Box::new(CLType::Key),
+            value: Box::new(CLType::U32),
+        }))),
+        Parameter::new(keys::TOKEN_PACKAGE_HASH, CLType::Key),
+        Parameter::new(keys::TOKEN_ID, CLType::String),
+        Parameter::new(keys::KYC_PACKAGE_HASH, CLType::Key),
+        Parameter::new(keys::KYC_MODE, CLType::Option(Box::new(CLType::U8))),
+        Parameter::new(keys::KYC_LEVEL, CLType::Option(Box::new(CLType::U256))),
+        Parameter::new(keys::KYC_RECHECK, CLType::Option(Box::new(CLType::Bool))),
+        Parameter::new(keys::SYNTHETIC_PACKAGE_HASH, CLType::Key),
+        Parameter::new(keys::START, CLType::U64),
+        Parameter::new(keys::END, CLType::U64),
+        Parameter::new(keys::MARKETPLACE_ACCOUNT, CLType::Option(Box::new(CLType::Key))),
+        Parameter::new(keys::MARKETPLACE_COMMISSION, CLType::Option(Box::new(CLType::U32))),
+        Parameter::new(keys::SETTLEMENT_TIMEOUT, CLType::Option(Box::new(CLType::U64))),
+    ]
+}
+
+fn page_parameters(mut parameters: Vec<Parameter>) -> Vec<Parameter> {
+    parameters.push(Parameter::new(keys::OFFSET, CLType::U32));
+    parameters.push(Parameter::new(keys::LIMIT, CLType::U32));
+    parameters
+}
+
+pub fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let mut english_parameters = auction_parameters();
+    english_parameters.extend(vec![
+        Parameter::new(keys::CANCEL, CLType::Option(Box::new(CLType::U64))),
+        Parameter::new(keys::RESERVE_PRICE, CLType::U512),
+        Parameter::new(keys::BIDDER_NUMBER_CAP, CLType::Option(Box::new(CLType::U64))),
+        Parameter::new(keys::AUCTION_TIMER_EXTENSION, CLType::Option(Box::new(CLType::U64))),
+        Parameter::new(keys::MINIMUM_BID_STEP, CLType::Option(Box::new(CLType::U512))),
+        Parameter::new(keys::MINIMUM_BID_INCREMENTS, CLType::Option(Box::new(CLType::Map {
+            key: Box::new(CLType::U512),
+            value: Box::new(CLType::U32),
+        }))),
+        Parameter::new(keys::SELLER_CANCELLATION_PENALTY, CLType::Option(Box::new(CLType::U32))),
+    ]);
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CREATE_ENGLISH_AUCTION,
+        english_parameters,
+        ContractPackageHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut dutch_parameters = auction_parameters();
+    dutch_parameters.extend(vec![
+        Parameter::new(keys::START_PRICE, CLType::U512),
+        Parameter::new(keys::RESERVE_PRICE, CLType::U512),
+    ]);
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CREATE_DUTCH_AUCTION,
+        dutch_parameters,
+        ContractPackageHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut swap_parameters = auction_parameters();
+    swap_parameters.push(Parameter::new(keys::SWAP_PRICE, CLType::U512));
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CREATE_SWAP,
+        swap_parameters,
+        ContractPackageHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CREATE_GIFT,
+        vec![
+            Parameter::new("contract_name", CLType::String),
+        ],
+        ContractPackageHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SYNC_STATUS,
+        vec![
+            Parameter::new(keys::AUCTION, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UPDATE_STATUS,
+        vec![
+            Parameter::new(keys::STATUS, CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UPGRADE_AUCTION,
+        vec![
+            Parameter::new(keys::AUCTION, CLType::Key),
+        ],
+        ContractHash::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTION_COUNT,
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTIONS,
+        page_parameters(vec![]),
+        Vec::<AuctionRecord>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTIONS_BY_SELLER,
+        page_parameters(vec![
+            Parameter::new(keys::SELLER, CLType::Key),
+        ]),
+        Vec::<AuctionRecord>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTIONS_BY_STATUS,
+        page_parameters(vec![
+            Parameter::new(keys::STATUS, CLType::U8),
+        ]),
+        Vec::<AuctionRecord>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTIONS_BY_TOKEN,
+        page_parameters(vec![
+            Parameter::new(keys::TOKEN_PACKAGE_HASH, CLType::Key),
+            Parameter::new(keys::TOKEN_ID, CLType::String),
+        ]),
+        Vec::<AuctionRecord>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::RESERVE_CREDIT,
+        vec![
+            Parameter::new(keys::BIDDER, CLType::Key),
+            Parameter::new(keys::AMOUNT, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::RELEASE_CREDIT,
+        vec![
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SETTLE_CREDIT,
+        vec![
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SET_CREDIT_LIMIT,
+        vec![
+            Parameter::new(keys::ACCOUNT, CLType::Key),
+            Parameter::new(keys::CREDIT_LIMIT, CLType::Option(Box::new(CLType::U512))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::REPAY_CREDIT,
+        vec![
+            Parameter::new(keys::ACCOUNT, CLType::Key),
+            Parameter::new(keys::AMOUNT, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SET_MARKETPLACE_SETTINGS,
+        vec![
+            Parameter::new(keys::MARKETPLACE_ACCOUNT, CLType::Key),
+            Parameter::new(keys::MAX_MARKETPLACE_COMMISSION, CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_MARKETPLACE_SETTINGS,
+        vec![],
+        MarketplaceSettings::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_CREDIT,
+        vec![
+            Parameter::new(keys::ACCOUNT, CLType::Key),
+        ],
+        CreditLine::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points
+}
+
+// Store the factory contract hash in the installing account
+fn put_factory_hash(factory_hash: ContractHash) {
+    runtime::put_key(
+        &format!("{}_contract_hash", keys::FACTORY_PACKAGE_HASH.trim_end_matches("_package_hash")),
+        Key::Hash(factory_hash.value()),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", keys::FACTORY_PACKAGE_HASH.trim_end_matches("_package_hash")),
+        storage::new_uref(factory_hash).into(),
+    );
+}
+
+/**
+ * Install the factory, or add a new version to the one this account installed before. Its registry,
+ * credit ledger and access tokens carry over, created contracts are upgraded with `upgrade_auction`.
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+    if let Some(factory_package) = runtime::get_key(keys::FACTORY_PACKAGE_HASH) {
+        let (factory_hash, _) = storage::add_contract_version(
+            ContractPackageHash::new(factory_package.into_hash().unwrap_or_revert()),
+            get_entry_points(),
+            NamedKeys::new(),
+        );
+        return put_factory_hash(factory_hash);
+    }
+
+    let mut named_keys = NamedKeys::new();
+    Registry::init(&mut named_keys);
+    CreditLedger::init(&mut named_keys);
+    // Marketplace settings every created auction is validated against, the admin can change them later
+    named_keys.insert(
+        String::from(keys::MARKETPLACE_ACCOUNT),
+        storage::new_uref(utils::string_to_account_hash(accounts::MARKETPLACE_ACCOUNT)).into(),
+    );
+    named_keys.insert(
+        String::from(keys::MAX_MARKETPLACE_COMMISSION),
+        storage::new_uref(accounts::MAX_MARKETPLACE_COMMISSION).into(),
+    );
+
+    let (factory_hash, _) = storage::new_contract(
+        get_entry_points(),
+        Some(named_keys),
+        Some(String::from(keys::FACTORY_PACKAGE_HASH)),
+        Some(String::from(keys::FACTORY_ACCESS_TOKEN)),
+    );
+    put_factory_hash(factory_hash);
+}
@@ -6,18 +6,35 @@ use casper_contract::{
     contract_api::{
         account::get_main_purse,
         runtime::{self, revert},
-        system::{create_purse, transfer_from_purse_to_purse},
+        system::{create_purse, get_purse_balance, transfer_from_purse_to_purse},
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
+use casper_private_auction_core::{functions, info::AuctionInfo};
 use casper_types::{runtime_args, ApiError, ContractHash, RuntimeArgs, URef, U512};
 
+// Bid `amount` and transfer all of it
+const MODE_FULL: &str = "full";
+// Bid `amount` and transfer only what the current funded bid does not cover
+const MODE_DELTA: &str = "delta";
+// Raise the current bid by `amount`
+const MODE_EXTEND: &str = "extend";
+// Escrow `amount` as the maximum of an English proxy bid, transferring what the current funded bid does not cover
+const MODE_PROXY: &str = "proxy";
+// Pay the Dutch or swap price, `amount` is the most the deploy may spend
+const MODE_BUY_NOW: &str = "buy_now";
+// Fund the synthetic winning bid, `amount` is the most the deploy may spend
+const MODE_FUND: &str = "fund";
+// Cancel an English auction with bids as its seller, `amount` is the most the penalty may take
+const MODE_PENALTY: &str = "penalty";
+
 #[no_mangle]
 pub extern "C" fn call() {
     // You are required to use an argument called "amount" for the amount that you transfer our of a main_purse
     let amount: U512 = runtime::get_named_arg("amount");
     let auction_contract: ContractHash = runtime::get_named_arg("auction_contract");
     let purse_name: String = runtime::get_named_arg("purse_name");
+    let mode: String = runtime::get_named_arg("mode");
     let bidder_purse: URef = match runtime::get_key(&purse_name) {
         Some(existing_purse) => existing_purse.into_uref().unwrap_or_revert(),
         None => {
@@ -26,17 +43,87 @@ pub extern "C" fn call() {
             new_purse
         }
     };
-    transfer_from_purse_to_purse(get_main_purse(), bidder_purse, amount, None).unwrap_or_revert();
+    // Bid of the caller and whether it is synthetic
+    let current_bid = || {
+        runtime::call_contract::<Option<(U512, bool)>>(auction_contract, functions::GET_BID, runtime_args! {})
+    };
+    // Funds the auction already holds for the caller
+    let funded_bid = || match current_bid() {
+        Some((bid, false)) => bid,
+        _ => U512::zero(),
+    };
+    let (entry_point, bid, needed) = match mode.as_str() {
+        MODE_FULL => (functions::BID, amount, amount),
+        MODE_DELTA => (functions::BID, amount, amount.saturating_sub(funded_bid())),
+        MODE_EXTEND => {
+            let pre_bid = current_bid().map(|(bid, _)| bid).unwrap_or_default();
+            let bid = pre_bid + amount;
+            (functions::BID, bid, bid.saturating_sub(funded_bid()))
+        }
+        // A leader raising its maximum may get part of this back below
+        MODE_PROXY => (functions::PROXY_BID, amount, amount.saturating_sub(funded_bid())),
+        MODE_BUY_NOW => {
+            let info = runtime::call_contract::<AuctionInfo>(auction_contract, functions::GET_AUCTION_INFO, runtime_args! {});
+            let price = info.current_price.or(info.swap_price).unwrap_or_revert_with(ApiError::User(103));
+            (functions::BID, price, price)
+        }
+        MODE_FUND => {
+            let bid = current_bid().map(|(bid, _)| bid).unwrap_or_revert_with(ApiError::User(103));
+            (functions::FUND_SYNTHETIC, bid, bid)
+        }
+        MODE_PENALTY => (functions::CANCEL_WITH_PENALTY, amount, amount),
+        _ => revert(ApiError::User(102)),
+    };
+    if (mode == MODE_BUY_NOW || mode == MODE_FUND) && needed > amount {
+        revert(ApiError::User(104));
+    }
+    // Leftovers of an earlier deploy count towards the transfer
+    let held = get_purse_balance(bidder_purse).unwrap_or_default();
+    if needed > held {
+        transfer_from_purse_to_purse(get_main_purse(), bidder_purse, needed - held, None).unwrap_or_revert();
+    }
     let bidder_purse_out = bidder_purse.into_read_write();
     if !bidder_purse_out.is_writeable() || !bidder_purse_out.is_readable() {
         revert(ApiError::User(101));
     }
-    runtime::call_contract::<()>(
-        auction_contract,
-        "bid",
-        runtime_args! {
-            "bid_purse" => bidder_purse_out,
-            "bid" => amount
-        },
-    );
+    if entry_point == functions::FUND_SYNTHETIC {
+        runtime::call_contract::<()>(
+            auction_contract,
+            entry_point,
+            runtime_args! {
+                "bid_purse" => bidder_purse_out
+            },
+        );
+    } else if entry_point == functions::CANCEL_WITH_PENALTY {
+        runtime::call_contract::<()>(
+            auction_contract,
+            entry_point,
+            runtime_args! {
+                "penalty_purse" => bidder_purse_out
+            },
+        );
+    } else if entry_point == functions::PROXY_BID {
+        runtime::call_contract::<()>(
+            auction_contract,
+            entry_point,
+            runtime_args! {
+                "bid_purse" => bidder_purse_out,
+                "max_bid" => bid
+            },
+        );
+    } else {
+        runtime::call_contract::<()>(
+            auction_contract,
+            entry_point,
+            runtime_args! {
+                "bid_purse" => bidder_purse_out,
+                "bid" => bid
+            },
+        );
+    }
+    // Whatever the auction did not take goes back to the main purse
+    let residual = get_purse_balance(bidder_purse).unwrap_or_default();
+    if !residual.is_zero() {
+        transfer_from_purse_to_purse(bidder_purse, get_main_purse(), residual, None).unwrap_or_revert();
+    }
 }
@@ -1,6 +1,8 @@
 // pub const MARKETPLACE_COMMISSION: u16 = 25;
 // pub const MARKETPLACE_ACCOUNT: &str = "account-hash-7de52a3013f609faa38ae99af4350da6aa6b69bec0e4087ecae87c2b9486a265";
 
-pub const MARKETPLACE_COMMISSION: u32 = 25;
+// Marketplace commissions are in basis points
+pub const MARKETPLACE_COMMISSION: u32 = 250;
+pub const MAX_MARKETPLACE_COMMISSION: u32 = 1000;
 pub const MARKETPLACE_ACCOUNT: &str = "account-hash-e1a2a648532b6333c66b4fe316bff945d4d51636f5bec52161331b9dd39d3122";
 pub const GIFT_ACCOUNT: &str = "account-hash-e1a2a648532b6333c66b4fe316bff945d4d51636f5bec52161331b9dd39d3122";
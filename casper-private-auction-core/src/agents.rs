@@ -0,0 +1,134 @@
+use casper_contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{system::CallStackElement, ContractPackageHash, Key, URef, U512};
+
+use crate::data::AuctionData;
+use crate::error::AuctionError;
+use crate::{keys, Dict};
+
+/**
+ * Contract packages the admin approved to bid on behalf of contract bidders, such as DAOs, multisig or custody
+ * contracts. An agent calls `bid` with the `bidder` (a `Key::Hash`) and a `refund_purse` the auction may deposit
+ * into. The bidder takes part under its own key, every refund of its bids goes to the refund purse and a won
+ * token to the bidder contract.
+ */
+pub struct BiddingAgents;
+
+impl BiddingAgents {
+    pub fn is_approved(agent: &ContractPackageHash) -> bool {
+        Dict::at(keys::BIDDING_AGENTS)
+            ._get::<bool>(&agent.to_formatted_string())
+            .unwrap_or_default()
+    }
+
+    // Approve agents, or withdraw their approval
+    pub fn update(agents: &[Key], approved: bool) {
+        let list = Dict::at(keys::BIDDING_AGENTS);
+        for agent in agents {
+            let agent = match agent {
+                Key::Hash(hash) => ContractPackageHash::new(*hash),
+                _ => runtime::revert(AuctionError::InvalidBiddingAgent),
+            };
+            if approved {
+                list.set(&agent.to_formatted_string(), true);
+            } else {
+                list._remove::<bool>(&agent.to_formatted_string());
+            }
+        }
+    }
+
+    // Approved agent the auction is called from, if any
+    pub fn calling_agent() -> Option<ContractPackageHash> {
+        let call_stack = runtime::get_call_stack();
+        match call_stack.len().checked_sub(2).and_then(|caller| call_stack.get(caller)) {
+            Some(CallStackElement::StoredContract { contract_package_hash, .. })
+                if Self::is_approved(contract_package_hash) => Some(*contract_package_hash),
+            _ => None,
+        }
+    }
+
+    /**
+     * Who a bid is placed for: the account of the session code, or the bidder an approved agent names.
+     * The agent and its refund purse are recorded for the bidder, a bidder stays with the agent it first bid through.
+     */
+    pub fn bidder() -> Key {
+        let agent = match Self::calling_agent() {
+            Some(agent) => agent,
+            None => {
+                // Standard bids have to be done via session code
+                if runtime::get_call_stack().len() != 2 {
+                    runtime::revert(AuctionError::DisallowedMiddleware);
+                }
+                return AuctionData::current_bidder();
+            }
+        };
+        let bidder = runtime::get_named_arg::<Key>(keys::BIDDER);
+        let refund_purse = runtime::get_named_arg::<URef>(keys::REFUND_PURSE);
+        if !refund_purse.is_addable() {
+            runtime::revert(AuctionError::RefundPurseNotAddable)
+        }
+        Self::check_agent(&bidder, &agent);
+        Dict::at(keys::AGENT_BIDDERS).set(&bidder.to_formatted_string(), (Key::Hash(agent.value()), refund_purse));
+        bidder
+    }
+
+    /**
+     * Bidder whose bid is cancelled: the caller, or the bidder an approved agent names, which must be one it bids for
+     */
+    pub fn canceller() -> Key {
+        match Self::calling_agent() {
+            Some(agent) => {
+                let bidder = runtime::get_named_arg::<Key>(keys::BIDDER);
+                Self::check_agent(&bidder, &agent);
+                bidder
+            }
+            None => AuctionData::current_caller(),
+        }
+    }
+
+    // Bidders are accounts, or contracts bidding through an agent
+    pub fn check_bidder(bidder: &Key) {
+        match bidder {
+            Key::Account(_) | Key::Hash(_) => (),
+            _ => runtime::revert(AuctionError::KeyNotAccount),
+        }
+    }
+
+    // Where the refunds of a bidder are paid: the purse its agent designated, else its account
+    pub fn refund_target(bidder: Key) -> Key {
+        match Self::record(&bidder) {
+            Some((_, refund_purse)) => Key::URef(refund_purse),
+            None => bidder,
+        }
+    }
+
+    pub fn refund(purse: URef, bidder: Key, amount: U512, error: AuctionError) {
+        let transfer = match (Self::record(&bidder), bidder) {
+            (Some((_, refund_purse)), _) => system::transfer_from_purse_to_purse(purse, refund_purse, amount, None),
+            (None, Key::Account(account)) => {
+                system::transfer_from_purse_to_account(purse, account, amount, None).map(|_| ())
+            }
+            // A contract bidder only bids through an agent, which gave a refund purse
+            (None, _) => runtime::revert(AuctionError::InvalidBiddingAgent),
+        };
+        transfer.unwrap_or_revert_with(error);
+    }
+
+    // A contract bidder may only be bid for by the agent that placed its bids so far
+    fn check_agent(bidder: &Key, agent: &ContractPackageHash) {
+        if !matches!(bidder, Key::Hash(_)) {
+            runtime::revert(AuctionError::InvalidBiddingAgent)
+        }
+        if let Some((recorded, _)) = Self::record(bidder) {
+            if recorded != Key::Hash(agent.value()) {
+                runtime::revert(AuctionError::InvalidBiddingAgent)
+            }
+        }
+    }
+
+    fn record(bidder: &Key) -> Option<(Key, URef)> {
+        Dict::at(keys::AGENT_BIDDERS)._get(&bidder.to_formatted_string())
+    }
+}
@@ -5,28 +5,41 @@ use casper_contract::{
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::account::AccountHash;
 pub use casper_types::bytesrepr::ToBytes;
 pub use casper_types::{
     bytesrepr::FromBytes, contracts::NamedKeys, runtime_args, system::CallStackElement, ApiError,
     CLTyped, ContractHash, Key, RuntimeArgs, URef, U512,
 };
 
+use crate::agents::BiddingAgents;
+use crate::commissions::{resolve_purse, resolve_purses, CommissionRecipient};
+use crate::credit::Credit;
 use crate::error::AuctionError;
+use crate::kyc::Kyc;
+use crate::pause::Pause;
+use crate::proxy::ProxyBids;
 use crate::{
     data::AuctionData,
     events::{emit, AuctionEvent},
 };
 use crate::accounts::MARKETPLACE_ACCOUNT;
-use crate::data::{AUCTION_PENDING_SETTLE, AUCTION_REJECTED, AUCTION_SETTLED};
-use crate::utils::string_to_account_hash;
+use crate::status::AuctionStatus;
+use crate::utils::{share_of, string_to_account_hash};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 
 pub struct Auction;
 
 impl Auction {
 
-    // Check if auction is still live
+    // Check if auction is still live and not paused
     pub fn check_valid() {
+        Pause::check();
+        Self::check_live();
+    }
+
+    // Check if auction is still live, regardless of a pause
+    pub fn check_live() {
         if !AuctionData::is_auction_live() || AuctionData::is_done() {
             runtime::revert(AuctionError::AuctionCompleted)
         }
@@ -64,6 +77,13 @@ impl Auction {
         }
     }
 
+    pub fn check_owner_or_admin() {
+        let caller = runtime::get_caller();
+        if AuctionData::token_owner() != Key::Account(caller) && string_to_account_hash(MARKETPLACE_ACCOUNT) != caller {
+            runtime::revert(AuctionError::InvalidCaller);
+        }
+    }
+
     pub fn move_bid_funds(bidder_purse: Option<URef>, bid: U512) {
         let auction_purse = AuctionData::auction_purse();
         let purse = bidder_purse.unwrap();
@@ -171,123 +191,431 @@ impl Auction {
         )
     }
 
+    // Pay a planned payout. A failed transfer is handed back instead of reverting the settlement.
+    fn try_pay(purse: URef, recipient: Key, amount: U512) -> Result<(), ApiError> {
+        // Refunds of agent bids go to the purse the agent designated
+        if let Key::URef(target) = recipient {
+            return system::transfer_from_purse_to_purse(purse, target, amount, None);
+        }
+        match CommissionRecipient::from_key(recipient).unwrap_or_revert() {
+            CommissionRecipient::Account(account) => {
+                system::transfer_from_purse_to_account(purse, account, amount, None).map(|_| ())
+            }
+            // Contracts receive their share into the purse they exposed when they were added
+            CommissionRecipient::Contract(_) => match AuctionData::recipient_purses().get(&recipient) {
+                Some(target) => system::transfer_from_purse_to_purse(purse, *target, amount, None),
+                None => Err(AuctionError::RecipientPurseNotAddable.into()),
+            },
+        }
+    }
+
+    /**
+     * Plan the distribution of a funded winning bid. Every share is computed in basis points and rounded down
+     * to the mote. Shares that round down to zero are skipped instead of paid, so their value,
+     * together with all rounding dust, stays in the proceeds that go to the beneficiaries.
+     * The receipt lists the computed split, stalled and redirected payouts are reported by their own events.
+     */
+    fn plan_distribution(winner: Key, amount: U512) -> Vec<(Key, U512)> {
+        let mut payouts: Vec<(Key, U512)> = Vec::new();
+        let mut skipped: Vec<Key> = Vec::new();
+
+        // Marketplace share first, then people get money
+        let (marketplace_account, marketplace_commission) = AuctionData::marketplace_data();
+        let marketplace = CommissionRecipient::Account(marketplace_account);
+        let market_share = share_of(amount, marketplace_commission);
+        if market_share.is_zero() {
+            skipped.push(marketplace.into());
+        } else {
+            payouts.push((marketplace.into(), market_share));
+        }
+        let proceeds = amount - market_share;
+
+        // Every actor receives their rate of the proceeds, the surplus goes to the designated beneficiary account.
+        let mut given_as_shares = U512::zero();
+        for (recipient, rate) in AuctionData::compute_commissions() {
+            let actor_share = share_of(proceeds, rate);
+            if actor_share.is_zero() {
+                skipped.push(recipient.into());
+                continue;
+            }
+            payouts.push((recipient.into(), actor_share));
+            given_as_shares += actor_share;
+        }
+
+        // The rest is split between the beneficiaries, the rounding dust of the split goes to the beneficiary account
+        let beneficiary_proceeds = proceeds - given_as_shares;
+        let mut beneficiary_shares: BTreeMap<CommissionRecipient, U512> = BTreeMap::new();
+        let mut given_to_beneficiaries = U512::zero();
+        for (recipient, rate) in AuctionData::beneficiaries() {
+            let beneficiary_share = share_of(beneficiary_proceeds, rate);
+            given_to_beneficiaries += beneficiary_share;
+            beneficiary_shares.insert(recipient, beneficiary_share);
+        }
+        let dust = beneficiary_proceeds - given_to_beneficiaries;
+        if !dust.is_zero() {
+            *beneficiary_shares
+                .entry(CommissionRecipient::Account(AuctionData::beneficiary_account()))
+                .or_insert_with(U512::zero) += dust;
+        }
+        for (recipient, beneficiary_share) in beneficiary_shares {
+            if beneficiary_share.is_zero() {
+                skipped.push(recipient.into());
+                continue;
+            }
+            payouts.push((recipient.into(), beneficiary_share));
+        }
+
+        emit(&AuctionEvent::SettlementReceipt { account: winner, bid: amount, payouts: payouts.clone(), skipped });
+        payouts
+    }
+
     /**
-     * Handle transferring the token and funds
+     * Handle transferring the token and funds. All payouts - shares of the winning bid, then refunds - are
+     * planned and stored before any is paid, and the progress is recorded as they go through.
+     * A failed transfer leaves the auction settling instead of reverting, `status` is only set once every payout is done.
      */
-    pub fn settle(winner: Option<AccountHash>) {
+    pub fn settle(winner: Option<Key>, status: AuctionStatus) {
+        AuctionData::check_transition(status);
+
         // If there is a winner, then move the token to the winner
         // else send it back to the owner
         match winner {
-            Some(acct) => Self::transfer_token(Key::Account(acct)),
+            Some(bidder) => Self::transfer_token(bidder),
             _ => Self::transfer_token(AuctionData::token_owner()),
         }
 
-        // fn return_bids(auction_purse: URef) {
-        //     let mut bids = AuctionData::bids();
-        //     for (bidder, bid) in &bids.to_map() {
-        //         // If the bid is synthetic - nothing to return
-        //         if !bid.1 {
-        //             system::transfer_from_purse_to_account(auction_purse, *bidder, bid.0.clone(), None)
-        //                 .unwrap_or_revert_with(AuctionError::AuctionEndReturnBids);
-        //         }
-        //     }
-        //     bids.clear();
-        // }
-        let auction_purse = AuctionData::auction_purse();
-        match winner {
-            Some(key) => {
-                let mut bids = AuctionData::bids();
-                match bids.get(&key) {
-                    Some(bid) => {
-                        if !bid.1 {
-                            // Marketplace share first, then people get money
-                            let (marketplace_account, marketplace_commission) =
-                                AuctionData::marketplace_data();
-                            let market_share = (bid.0 * marketplace_commission) / 1000;
-                            system::transfer_from_purse_to_account(
-                                auction_purse,
-                                marketplace_account,
-                                market_share,
-                                None,
-                            )
-                                .unwrap_or_revert_with(AuctionError::TransferMarketPlaceShare);
-                            let proceeds = bid.0 - market_share;
-                            // Every actor receives x one-thousandth of the winning bid, the surplus goes to the designated beneficiary account.
-                            let mut given_as_shares = U512::zero();
-                            for (account, share) in AuctionData::compute_commissions() {
-                                let actor_share = (proceeds * share) / 1000;
-                                if actor_share == U512::from(0_u64) {
-                                    runtime::revert(AuctionError::BadState);
-                                }
-                                system::transfer_from_purse_to_account(
-                                    auction_purse,
-                                    account,
-                                    actor_share,
-                                    None,
-                                )
-                                    .unwrap_or_revert_with(AuctionError::TransferCommissionShare);
-                                given_as_shares += actor_share;
-                            }
-                            system::transfer_from_purse_to_account(
-                                auction_purse,
-                                AuctionData::beneficiary_account(),
-                                proceeds - given_as_shares,
-                                None,
-                            )
-                                .unwrap_or_revert_with(AuctionError::TransferBeneficiaryShare);
-                            bids.remove_by_key(&key);
-                        }
-                        // return_bids(auction_purse);
-                    }
-                    // Something went wrong, so better return everyone's money
-                    _ => {
-                        // skip
+        let mut payouts: Vec<(Key, U512)> = Vec::new();
+        let mut bids = AuctionData::bids();
+        if let Some(key) = winner {
+            if let Some(bid) = bids.get(&key) {
+                if !bid.1 {
+                    payouts.append(&mut Self::plan_distribution(key, bid.0));
+                    // What a proxy escrowed above the winning bid goes back
+                    let escrow = ProxyBids::take_escrow(&key, bid.0);
+                    if escrow > bid.0 {
+                        payouts.push((BiddingAgents::refund_target(key), escrow - bid.0));
                     }
+                } else {
+                    // The credit the synthetic bid held becomes an obligation
+                    Credit::settle(key);
                 }
-            }
-            _ => {
-                // return_bids(auction_purse);
+                bids.remove_by_key(&key);
             }
         }
-        let mut bids = AuctionData::bids();
         for (bidder, bid) in &bids.to_map() {
-            // If the bid is synthetic - nothing to return
+            // If the bid is synthetic - nothing to return, only its credit to release
             if !bid.1 {
-                system::transfer_from_purse_to_account(auction_purse, *bidder, bid.0.clone(), None)
-                    .unwrap_or_revert_with(AuctionError::AuctionEndReturnBids);
+                payouts.push((BiddingAgents::refund_target(*bidder), ProxyBids::take_escrow(bidder, bid.0)));
+            } else {
+                Credit::release(*bidder);
             }
         }
         bids.clear();
+
+        AuctionData::set_settlement_payouts(payouts);
+        AuctionData::set_settlement_progress(0);
+        AuctionData::set_settlement_status(status);
+        AuctionData::update_status(AuctionStatus::Settling);
+        Self::run_payouts();
+    }
+
+    // Pay the planned payouts from the recorded progress on, stop at the first one that fails
+    fn run_payouts() {
+        let auction_purse = AuctionData::auction_purse();
+        let payouts = AuctionData::settlement_payouts();
+        let mut progress = AuctionData::settlement_progress();
+        while let Some((recipient, amount)) = payouts.get(progress as usize) {
+            if Self::try_pay(auction_purse, *recipient, *amount).is_err() {
+                AuctionData::set_settlement_progress(progress);
+                emit(&AuctionEvent::SettlementStalled { account: *recipient, amount: *amount });
+                return;
+            }
+            progress += 1;
+        }
+        AuctionData::set_settlement_progress(progress);
+        let status = AuctionData::settlement_status();
+        AuctionData::update_status(status);
+        // Settled and Cancelled are only reported once the auction really is
+        match status {
+            AuctionStatus::Settled => {
+                let (account, bid) = AuctionData::current_winner();
+                emit(&AuctionEvent::Settled { account, bid })
+            }
+            AuctionStatus::Cancelled => emit(&AuctionEvent::Cancelled {}),
+            _ => (),
+        }
+    }
+
+    /**
+     * Retry a stalled settlement, starting from the payout that failed
+     */
+    pub fn resume_settlement() {
+        AuctionData::check_transition_from(AuctionStatus::Settling, AuctionData::settlement_status());
+        Self::run_payouts();
+    }
+
+    /**
+     * Send the stalled payout to a holding account instead and carry on settling - only callable by admin
+     */
+    pub fn redirect_payout(holding_account: Key) {
+        // Only admin is allowed to call this
+        Self::check_admin();
+
+        AuctionData::check_transition_from(AuctionStatus::Settling, AuctionData::settlement_status());
+        if let CommissionRecipient::Contract(package_hash) = CommissionRecipient::from_key(holding_account)
+            .unwrap_or_revert_with(AuctionError::InvalidHoldingAccount)
+        {
+            let mut purses = BTreeMap::new();
+            purses.insert(holding_account, resolve_purse(package_hash));
+            AuctionData::add_recipient_purses(purses);
+        }
+
+        let mut payouts = AuctionData::settlement_payouts();
+        let payout = payouts
+            .get_mut(AuctionData::settlement_progress() as usize)
+            .unwrap_or_revert_with(AuctionError::BadState);
+        let (account, amount) = *payout;
+        payout.0 = holding_account;
+        AuctionData::set_settlement_payouts(payouts);
+        emit(&AuctionEvent::PayoutRedirected { account, holding_account, amount });
+
+        Self::run_payouts();
+    }
+
+    /**
+     * Check the winner against the gating again, if the auction asks for it. A winner that is no longer
+     * verified is refunded and dropped, and the next-highest bid is checked in turn.
+     * Returns whether the winner changed.
+     */
+    pub fn recheck_winner() -> bool {
+        if !Kyc::recheck() {
+            return false;
+        }
+        let mut bids = AuctionData::bids();
+        let mut changed = false;
+        loop {
+            let (winner, bid, synthetic) = bids.max_by_key();
+            match (winner, bid) {
+                (Some(account), Some(amount)) if !Kyc::is_verified(&account) => {
+                    // If the bid was not synthetic, then return it
+                    if !synthetic {
+                        let refund = ProxyBids::take_escrow(&account, amount);
+                        BiddingAgents::refund(AuctionData::auction_purse(), account, refund, AuctionError::RefundDisqualifiedBid);
+                    } else {
+                        Credit::release(account);
+                    }
+                    bids.remove_by_key(&account);
+                    changed = true;
+                    emit(&AuctionEvent::WinnerDisqualified { account, bid: (amount, synthetic) });
+                }
+                _ => {
+                    if changed {
+                        AuctionData::update_current_winner(winner, bid, synthetic);
+                    }
+                    return changed;
+                }
+            }
+        }
+    }
+
+    /**
+     * Hold a synthetic winning bid until it is approved or rejected. The settlement deadline, if the auction has one, starts now.
+     */
+    pub fn await_settlement(account: Key, bid: (U512, bool)) {
+        if AuctionData::status() != AuctionStatus::PendingSettle {
+            AuctionData::update_status(AuctionStatus::PendingSettle);
+        }
+        let now = u64::from(runtime::get_blocktime());
+        AuctionData::set_settlement_deadline(AuctionData::settlement_timeout().map(|timeout| now + timeout));
+        emit(&AuctionEvent::PendingSettlement { account, bid })
     }
 
     pub fn approve() {
         // Only admin is allowed to call this
         Self::check_admin();
 
-        if AuctionData::status() != AUCTION_PENDING_SETTLE {
-            runtime::revert(AuctionError::BadState)
+        // Only an auction waiting for approval can be approved or rejected
+        AuctionData::check_transition_from(AuctionStatus::PendingSettle, AuctionStatus::Settled);
+
+        // A synthetic bid that takes over from a disqualified winner needs its own approval,
+        // and with every bidder disqualified there is nothing left to settle
+        if Self::recheck_winner() {
+            match AuctionData::current_winner() {
+                (Some(account), Some(bid)) if bid.1 => return Self::await_settlement(account, bid),
+                (None, _) => return Self::settle(Option::None, AuctionStatus::Rejected),
+                _ => (),
+            }
         }
 
         // Get the winner
-        let (winner, bid) = AuctionData::current_winner();
-        Self::settle(winner.into());
-        AuctionData::update_status(AUCTION_SETTLED);
-        emit(&AuctionEvent::Settled { account: winner, bid })
+        let (winner, _bid) = AuctionData::current_winner();
+        Self::settle(winner, AuctionStatus::Settled);
+    }
+
+    /**
+     * Withdraw the synthetic bid a Dutch auction or swap is waiting on. The auction reopens if it has time left,
+     * otherwise it ends rejected.
+     */
+    pub fn withdraw_synthetic(bidder: Key) {
+        // Reopens or ends rejected, both only out of a pending settlement
+        AuctionData::check_transition_from(AuctionStatus::PendingSettle, AuctionStatus::Rejected);
+        match AuctionData::current_winner() {
+            (Some(winner), Some((_, true))) if winner == bidder => (),
+            _ => runtime::revert(AuctionError::NoBid),
+        }
+
+        AuctionData::bids().remove_by_key(&bidder);
+        Credit::release(bidder);
+        AuctionData::update_current_winner(None, None, false);
+        AuctionData::set_settlement_deadline(None);
+        emit(&AuctionEvent::BidCancelled { account: bidder });
+
+        if u64::from(runtime::get_blocktime()) < AuctionData::end_time() {
+            AuctionData::update_status(AuctionStatus::Live);
+        } else {
+            Self::settle(Option::None, AuctionStatus::Rejected);
+            emit(&AuctionEvent::SettlementRejected { account: Some(bidder) })
+        }
+    }
+
+    /**
+     * Settle a synthetic win with real funds - only callable by the winner, or admin on their behalf, while
+     * the settlement is pending. The deposit turns the winning bid into a funded one, distributed like any other.
+     */
+    pub fn fund_synthetic(funding_purse: URef) {
+        AuctionData::check_transition_from(AuctionStatus::PendingSettle, AuctionStatus::Settled);
+        let (winner, amount) = match AuctionData::current_winner() {
+            (Some(winner), Some((amount, true))) => (winner, amount),
+            _ => runtime::revert(AuctionError::BadState),
+        };
+        let caller = runtime::get_caller();
+        if Key::Account(caller) != winner && string_to_account_hash(MARKETPLACE_ACCOUNT) != caller {
+            runtime::revert(AuctionError::InvalidCaller);
+        }
+        if Kyc::recheck() {
+            Self::verify(&winner);
+        }
+
+        Self::move_bid_funds(Some(funding_purse), amount);
+        AuctionData::bids().replace(&winner, amount, false);
+        AuctionData::update_current_winner(Some(winner), Some(amount), false);
+        // The bid no longer holds synthetic credit
+        Credit::release(winner);
+
+        Self::settle(Some(winner), AuctionStatus::Settled);
+    }
+
+    /**
+     * Re-read the commission table from the token - only callable by admin while the auction is not done
+     */
+    pub fn refresh_commissions() {
+        // Only admin is allowed to call this
+        Self::check_admin();
+
+        if AuctionData::is_done() {
+            runtime::revert(AuctionError::AuctionCompleted)
+        }
+
+        let commissions = AuctionData::fetch_commissions();
+        let recipients: Vec<Key> = commissions.keys().map(|recipient| (*recipient).into()).collect();
+        AuctionData::add_recipient_purses(resolve_purses(recipients.iter()));
+        AuctionData::set_commissions(commissions);
+        emit(&AuctionEvent::CommissionsRefreshed {})
+    }
+
+    /**
+     * Add accounts to or take them off the allowlist or denylist - only callable by owner or admin while the auction is not done
+     */
+    pub fn update_gating_list(accounts: Vec<Key>, listed: bool) {
+        Self::check_owner_or_admin();
+
+        if AuctionData::is_done() {
+            runtime::revert(AuctionError::AuctionCompleted)
+        }
+
+        Kyc::update_list(&accounts, listed);
+        emit(&AuctionEvent::GatingListUpdated { accounts: accounts.len() as u32, listed })
+    }
+
+    /**
+     * Approve contract packages as bidding agents, or withdraw their approval - only callable by admin while the auction is not done
+     */
+    pub fn update_bidding_agents(agents: Vec<Key>, approved: bool) {
+        // Only admin is allowed to call this
+        Self::check_admin();
+
+        if AuctionData::is_done() {
+            runtime::revert(AuctionError::AuctionCompleted)
+        }
+
+        BiddingAgents::update(&agents, approved);
+        emit(&AuctionEvent::BiddingAgentsUpdated { agents: agents.len() as u32, approved })
+    }
+
+    /**
+     * Freeze bidding and finalization - only callable by admin while the auction is not done
+     */
+    pub fn pause() {
+        // Only admin is allowed to call this
+        Self::check_admin();
+
+        if AuctionData::is_done() {
+            runtime::revert(AuctionError::AuctionCompleted)
+        }
+        Pause::pause();
     }
 
-    pub fn reject() {
+    /**
+     * Lift the pause - only callable by admin. The schedule can be pushed back by the paused duration.
+     */
+    pub fn unpause(extend_end_time: bool) {
         // Only admin is allowed to call this
         Self::check_admin();
 
-        if AuctionData::status() != AUCTION_PENDING_SETTLE {
-            runtime::revert(AuctionError::BadState)
+        let duration = Pause::unpause();
+        if extend_end_time {
+            AuctionData::delay_times(duration);
+        }
+    }
+
+    /**
+     * Reject a pending settlement - only callable by admin until the settlement deadline, by anyone after it.
+     * With `offer_next` the token goes to the next-highest remaining bid instead of back to the owner.
+     */
+    pub fn reject(offer_next: bool) {
+        if !AuctionData::is_settlement_overdue() {
+            // Only admin is allowed to call this
+            Self::check_admin();
         }
 
+        // Only an auction waiting for approval can be approved or rejected
+        AuctionData::check_transition_from(AuctionStatus::PendingSettle, AuctionStatus::Rejected);
+
         // Get the winner (who did not settle)
         let (winner, _bid) = AuctionData::current_winner();
-        Self::settle(Option::None);
-        AuctionData::update_status(AUCTION_REJECTED);
-        emit(&AuctionEvent::SettlementRejected { account: winner })
+        if !offer_next {
+            Self::settle(Option::None, AuctionStatus::Rejected);
+            emit(&AuctionEvent::SettlementRejected { account: winner });
+            return;
+        }
+
+        // Drop the synthetic winner and fall back to the next-highest bid
+        if let Some(account) = winner {
+            let mut bids = AuctionData::bids();
+            bids.remove_by_key(&account);
+            Credit::release(account);
+            let (next_winner, next_bid, next_synth) = bids.max_by_key();
+            AuctionData::update_current_winner(next_winner, next_bid, next_synth);
+        }
+        emit(&AuctionEvent::SettlementRejected { account: winner });
+        Self::recheck_winner();
+
+        match AuctionData::current_winner() {
+            // Another synthetic bid needs its own settlement
+            (Some(account), Some(bid)) if bid.1 => Self::await_settlement(account, bid),
+            (Some(account), Some(_)) => Self::settle(Some(account), AuctionStatus::Settled),
+            _ => Self::settle(Option::None, AuctionStatus::Rejected),
+        }
     }
 
     //
@@ -1,6 +1,6 @@
 use core::iter::FromIterator;
 
-use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::{String, ToString}, vec::Vec};
 use casper_contract::{
     contract_api::{
         runtime::{self},
@@ -11,6 +11,12 @@ use casper_contract::{
 use casper_types::{account::AccountHash, Key, URef, U512};
 
 use crate::error::AuctionError;
+use crate::utils::dict_key;
+
+// Bidders are accounts, or contracts bidding through an agent, stored under their formatted key
+fn item_key(key: &Key) -> String {
+    dict_key(&key.to_formatted_string())
+}
 
 pub struct Bids {
     key_uref: URef,
@@ -54,22 +60,22 @@ impl Bids {
     }
 
     // Get a key corresponding to an index.
-    pub fn get_key_by_index(&self, index: u64) -> Option<AccountHash> {
+    pub fn get_key_by_index(&self, index: u64) -> Option<Key> {
         storage::dictionary_get(self.index_uref, &index.to_string())
             .unwrap_or_revert_with(AuctionError::DictionaryGetFailGetByIndex)
             .unwrap_or_default()
     }
 
     // Return the index a key is stored under.
-    pub fn get_index_by_key(&self, key: &AccountHash) -> Option<u64> {
-        storage::dictionary_get(self.index_uref, &key.to_string())
+    pub fn get_index_by_key(&self, key: &Key) -> Option<u64> {
+        storage::dictionary_get(self.index_uref, &item_key(key))
             .unwrap_or_revert_with(AuctionError::DictionaryGetFailGetByKey)
             .unwrap_or_default()
     }
 
     // If exists, returns the value stored under a key.
-    pub fn get(&self, key: &AccountHash) -> Option<(U512, bool)> {
-        storage::dictionary_get(self.key_uref, &key.to_string())
+    pub fn get(&self, key: &Key) -> Option<(U512, bool)> {
+        storage::dictionary_get(self.key_uref, &item_key(key))
             .unwrap_or_revert_with(AuctionError::DictionaryGetFailBidsGet)
             .unwrap_or_default()
     }
@@ -83,25 +89,25 @@ impl Bids {
         None
     }
 
-    pub fn insert(&mut self, key: &AccountHash, value: U512, synthetic: bool) {
+    pub fn insert(&mut self, key: &Key, value: U512, synthetic: bool) {
         self.insert_key_value(key, Some((value, synthetic)));
         self.insert_key_index(self.len, key);
         self.set_len(self.len + 1);
     }
 
     // // Public method for adding new entry to the Bids. If key already exists, does nothing.
-    // pub fn insert(&mut self, key: &AccountHash, value: U512, synthetic: bool) {
+    // pub fn insert(&mut self, key: &Key, value: U512, synthetic: bool) {
     //     if self.get(key).is_none() {
     //         self.raw_insert(key, value, synthetic);
     //     }
     // }
 
-    pub fn replace(&mut self, key: &AccountHash, value: U512, synthetic: bool) {
+    pub fn replace(&mut self, key: &Key, value: U512, synthetic: bool) {
         self.insert_key_value(key, Some((value, synthetic)));
     }
 
     // // Replaces as existing entry, or if one is not present, inserts a new one.
-    // pub fn replace(&mut self, key: &AccountHash, value: U512, synthetic: bool) {
+    // pub fn replace(&mut self, key: &Key, value: U512, synthetic: bool) {
     //     if self.get(key).is_some() {
     //         self.insert_key_value(key, Some((value, synthetic)));
     //         return;
@@ -110,7 +116,7 @@ impl Bids {
     // }
 
     // // Switch key at index. Can also replace value at that key.
-    // pub fn replace_index(&mut self, index: u64, key: &AccountHash, value: Option<U512>) {
+    // pub fn replace_index(&mut self, index: u64, key: &Key, value: Option<U512>) {
     //     if self.nth(index).is_some() {
     //         self.set_key_to_index(index, key);
     //         if let Some(v) = value {
@@ -124,7 +130,7 @@ impl Bids {
     // }
 
     // If an index is associated to the key, calls `remove_by_index`, and in case an index is not present still tries to remove the value at the key.
-    pub fn remove_by_key(&mut self, key: &AccountHash) {
+    pub fn remove_by_key(&mut self, key: &Key) {
         match self.get_index_by_key(key) {
             Some(index) => {
                 self.remove_by_index(index);
@@ -160,16 +166,16 @@ impl Bids {
     }
 
     // Removes and returns the data and key at the last index from the dictionary.
-    pub fn pop(&mut self) -> Option<(AccountHash, (U512, bool))> {
+    pub fn pop(&mut self) -> Option<(Key, (U512, bool))> {
         let index = self.len - 1;
         if let Some(key) = self.get_key_by_index(index) {
             if let Some(value) = self.get(&key) {
                 storage::dictionary_put(
                     self.index_uref,
                     &index.to_string(),
-                    Option::<AccountHash>::None,
+                    Option::<Key>::None,
                 );
-                storage::dictionary_put(self.index_uref, &key.to_string(), Option::<u64>::None);
+                storage::dictionary_put(self.index_uref, &item_key(&key), Option::<u64>::None);
                 self.insert_key_value(&key, Option::<(U512, bool)>::None);
                 self.set_len(index);
                 return Some((key, value));
@@ -186,13 +192,13 @@ impl Bids {
         self.len == 0
     }
 
-    fn insert_key_value(&self, key: &AccountHash, value: Option<(U512, bool)>) {
-        storage::dictionary_put(self.key_uref, &key.to_string(), value);
+    fn insert_key_value(&self, key: &Key, value: Option<(U512, bool)>) {
+        storage::dictionary_put(self.key_uref, &item_key(key), value);
     }
 
-    fn insert_key_index(&self, index: u64, key: &AccountHash) {
+    fn insert_key_index(&self, index: u64, key: &Key) {
         storage::dictionary_put(self.index_uref, &index.to_string(), Some(*key));
-        storage::dictionary_put(self.index_uref, &key.to_string(), Some(index));
+        storage::dictionary_put(self.index_uref, &item_key(key), Some(index));
     }
 
     fn set_len(&mut self, length: u64) {
@@ -200,8 +206,8 @@ impl Bids {
         self.len = length;
     }
 
-    pub fn to_map(&self) -> BTreeMap<AccountHash, (U512, bool)> {
-        let mut ret: BTreeMap<AccountHash, (U512, bool)> = BTreeMap::new();
+    pub fn to_map(&self) -> BTreeMap<Key, (U512, bool)> {
+        let mut ret: BTreeMap<Key, (U512, bool)> = BTreeMap::new();
         for i in 0..self.len {
             let key = self
                 .get_key_by_index(i)
@@ -214,13 +220,32 @@ impl Bids {
         ret
     }
 
+    // Bids of state version 0 were stored under the account hash of the bidder
+    pub fn rekey_accounts(&self) {
+        for index in 0..self.len {
+            let account: Option<AccountHash> = storage::dictionary_get(self.index_uref, &index.to_string())
+                .unwrap_or_revert_with(AuctionError::DictionaryGetFailGetByIndex)
+                .unwrap_or_default();
+            if let Some(account) = account {
+                let value: Option<(U512, bool)> = storage::dictionary_get(self.key_uref, &account.to_string())
+                    .unwrap_or_revert_with(AuctionError::DictionaryGetFailBidsGet)
+                    .unwrap_or_default();
+                storage::dictionary_put(self.key_uref, &account.to_string(), Option::<(U512, bool)>::None);
+                storage::dictionary_put(self.index_uref, &account.to_string(), Option::<u64>::None);
+                let key = Key::Account(account);
+                self.insert_key_value(&key, value);
+                self.insert_key_index(index, &key);
+            }
+        }
+    }
+
     pub fn clear(&mut self) {
         for _ in 0..self.len {
             self.pop();
         }
     }
 
-    pub fn max_by_key(&self) -> (Option<AccountHash>, Option<U512>, bool) {
+    pub fn max_by_key(&self) -> (Option<Key>, Option<U512>, bool) {
         if !self.is_empty() {
             let mut max_key = self
                 .get_key_by_index(0)
@@ -245,8 +270,8 @@ impl Bids {
         (None, None, false)
     }
 
-    /// Returns the account hash of the lowest bidder if the new bid is higher
-    pub fn get_lowest_bid(&self, new_item: &U512) -> Option<(AccountHash, (U512, bool))> {
+    /// Returns the key of the lowest bidder if the new bid is higher
+    pub fn get_lowest_bid(&self, new_item: &U512) -> Option<(Key, (U512, bool))> {
         let mut bidders = Vec::from_iter(self.to_map());
         bidders.sort_by(|&(_, a), &(_, b)| b.cmp(&a));
         let (lowest_bidder, lowest_bid) = bidders
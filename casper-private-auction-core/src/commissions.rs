@@ -0,0 +1,183 @@
+use alloc::{collections::BTreeMap, format, string::String};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{account::AccountHash, runtime_args, ContractPackageHash, Key, PublicKey, RuntimeArgs, URef};
+
+use crate::error::AuctionError;
+use crate::functions;
+use crate::utils::BASIS_POINTS;
+
+const ACCOUNT_TAG: &str = "account";
+const RATE_TAG: &str = "rate";
+
+/// Recipient of a commission share, either an account or a contract exposing a purse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommissionRecipient {
+    Account(AccountHash),
+    Contract(ContractPackageHash),
+}
+
+impl From<CommissionRecipient> for Key {
+    fn from(recipient: CommissionRecipient) -> Key {
+        match recipient {
+            CommissionRecipient::Account(account) => Key::Account(account),
+            CommissionRecipient::Contract(package_hash) => Key::Hash(package_hash.value()),
+        }
+    }
+}
+
+impl CommissionRecipient {
+    pub fn from_key(key: Key) -> Result<CommissionRecipient, AuctionError> {
+        match key {
+            Key::Account(account) => Ok(CommissionRecipient::Account(account)),
+            Key::Hash(hash) => Ok(CommissionRecipient::Contract(ContractPackageHash::new(hash))),
+            _ => Err(AuctionError::CommissionAccountIncorrectSerialization),
+        }
+    }
+}
+
+/**
+ * Parses a commission recipient. Accepted formats are:
+ * `account-hash-<hex>`, `Key::Account(<hex>)`, a bare account hash `<hex>`,
+ * a hex encoded public key, `hash-<hex>`, `Key::Hash(<hex>)` and `contract-package-wasm<hex>`.
+ */
+pub fn parse_recipient(recipient: &str) -> Result<CommissionRecipient, AuctionError> {
+    if let Some(inner) = recipient.strip_prefix("Key::Account(").and_then(|r| r.strip_suffix(')')) {
+        return parse_account_hash(inner);
+    }
+    if let Some(inner) = recipient.strip_prefix("Key::Hash(").and_then(|r| r.strip_suffix(')')) {
+        return parse_package_hash(&format!("hash-{}", inner));
+    }
+    if recipient.starts_with("account-hash-") {
+        return AccountHash::from_formatted_str(recipient)
+            .map(CommissionRecipient::Account)
+            .map_err(|_| AuctionError::CommissionAccountIncorrectSerialization);
+    }
+    if recipient.starts_with("hash-") {
+        return parse_package_hash(recipient);
+    }
+    if recipient.starts_with("contract-package-") {
+        return ContractPackageHash::from_formatted_str(recipient)
+            .map(CommissionRecipient::Contract)
+            .map_err(|_| AuctionError::CommissionContractIncorrectSerialization);
+    }
+    if recipient.len() == 64 {
+        return parse_account_hash(recipient);
+    }
+    PublicKey::from_hex(recipient)
+        .map(|public_key| CommissionRecipient::Account(public_key.to_account_hash()))
+        .map_err(|_| AuctionError::CommissionPublicKeyIncorrectSerialization)
+}
+
+fn parse_account_hash(hex: &str) -> Result<CommissionRecipient, AuctionError> {
+    AccountHash::from_formatted_str(&format!("account-hash-{}", hex))
+        .map(CommissionRecipient::Account)
+        .map_err(|_| AuctionError::CommissionAccountIncorrectSerialization)
+}
+
+fn parse_package_hash(formatted: &str) -> Result<CommissionRecipient, AuctionError> {
+    Key::from_formatted_str(formatted)
+        .ok()
+        .and_then(|key| key.into_hash())
+        .map(|hash| CommissionRecipient::Contract(ContractPackageHash::new(hash)))
+        .ok_or(AuctionError::CommissionContractIncorrectSerialization)
+}
+
+/**
+ * Parses a commission rate given in thousandths into basis points.
+ * A single decimal place is allowed, e.g. `2.5` is 25 basis points.
+ */
+pub fn parse_rate(rate: &str) -> Result<u32, AuctionError> {
+    let (whole, fraction) = rate.split_once('.').unwrap_or((rate, ""));
+    let whole = whole
+        .parse::<u32>()
+        .map_err(|_| AuctionError::CommissionRateIncorrectSerialization)?;
+    let fraction = match fraction.len() {
+        0 => 0,
+        1 => fraction
+            .parse::<u32>()
+            .map_err(|_| AuctionError::CommissionRateIncorrectSerialization)?,
+        _ => return Err(AuctionError::CommissionRateTooPrecise),
+    };
+    whole
+        .checked_mul(10)
+        .and_then(|w| w.checked_add(fraction))
+        .ok_or(AuctionError::CommissionTooManyShares)
+}
+
+/**
+ * Parses the `<actor>_account` / `<actor>_rate` pairs of an NFT commission table.
+ * Rates are returned in basis points, recipients listed more than once have their rates added up.
+ */
+pub fn parse_commissions(
+    commissions: &BTreeMap<String, String>,
+) -> Result<BTreeMap<CommissionRecipient, u32>, AuctionError> {
+    let mut parsed: BTreeMap<CommissionRecipient, u32> = BTreeMap::new();
+    let mut share_sum: u32 = 0;
+    for (key, value) in commissions {
+        let (actor, property) = key
+            .rsplit_once('_')
+            .ok_or(AuctionError::CommissionPropertySplit)?;
+        if actor.is_empty() {
+            return Err(AuctionError::CommissionActorSplit);
+        }
+        match property {
+            ACCOUNT_TAG => {
+                let rate = commissions
+                    .get(&format!("{}_{}", actor, RATE_TAG))
+                    .ok_or(AuctionError::MismatchedCommissionAccount)?;
+                let recipient = parse_recipient(value)?;
+                let rate = parse_rate(rate)?;
+                share_sum = share_sum
+                    .checked_add(rate)
+                    .ok_or(AuctionError::CommissionTooManyShares)?;
+                *parsed.entry(recipient).or_insert(0) += rate;
+            }
+            RATE_TAG => {
+                if !commissions.contains_key(&format!("{}_{}", actor, ACCOUNT_TAG)) {
+                    return Err(AuctionError::MismatchedCommissionRate);
+                }
+            }
+            _ => return Err(AuctionError::InvalidcommissionProperty),
+        }
+    }
+    if share_sum > BASIS_POINTS {
+        return Err(AuctionError::CommissionTooManyShares);
+    }
+    Ok(parsed)
+}
+
+// Commission tables are stored on chain keyed by the recipient `Key`
+pub fn to_keyed_commissions(commissions: BTreeMap<CommissionRecipient, u32>) -> BTreeMap<Key, u32> {
+    commissions
+        .into_iter()
+        .map(|(recipient, rate)| (recipient.into(), rate))
+        .collect()
+}
+
+pub fn from_keyed_commissions(commissions: BTreeMap<Key, u32>) -> BTreeMap<CommissionRecipient, u32> {
+    commissions
+        .into_iter()
+        .map(|(key, rate)| (CommissionRecipient::from_key(key).unwrap_or_revert(), rate))
+        .collect()
+}
+
+/**
+ * Purses of the contract recipients among `recipients`, read from their `get_purse` entry point.
+ * They are resolved when the recipients are set, so a settlement never calls into a recipient contract.
+ */
+pub fn resolve_purses<'a>(recipients: impl Iterator<Item = &'a Key>) -> BTreeMap<Key, URef> {
+    recipients
+        .filter_map(|recipient| match CommissionRecipient::from_key(*recipient) {
+            Ok(CommissionRecipient::Contract(package_hash)) => Some((*recipient, resolve_purse(package_hash))),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn resolve_purse(package_hash: ContractPackageHash) -> URef {
+    let purse = runtime::call_versioned_contract::<URef>(package_hash, None, functions::GET_PURSE, runtime_args! {});
+    if !purse.is_addable() {
+        runtime::revert(AuctionError::RecipientPurseNotAddable)
+    }
+    purse
+}
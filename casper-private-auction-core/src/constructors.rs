@@ -1,12 +1,15 @@
+use alloc::collections::BTreeMap;
 use alloc::string::String;
+use alloc::vec::Vec;
 use casper_contract::contract_api::runtime;
 use casper_contract::contract_api::storage;
-use casper_contract::contract_api::runtime::revert;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{ContractPackageHash, Key, U512, account::AccountHash};
+use casper_types::{ContractPackageHash, Key, U256, U512, account::AccountHash};
 use casper_types::contracts::NamedKeys;
-use crate::{AuctionError, keys, utils};
-use crate::data::{AuctionData};
+use crate::{AuctionError, accounts, commissions, keys, migrations, utils};
+use crate::data::AuctionData;
+use crate::kyc::KycMode;
+use crate::status::{AuctionStatus, ContractType};
 
 macro_rules! named_keys {
     ( $( ($name:expr, $value:expr) ),* ) => {
@@ -55,32 +58,11 @@ fn get_fixed_times() -> (u64, u64) {
     runtime::revert(AuctionError::InvalidTimes)
 }
 
-fn validate_commissions(token_id: &String, token_package_hash: &ContractPackageHash) {
+// Validate the commission structure in the NFT and freeze it for the lifetime of the auction
+fn snapshot_commissions(token_id: &String, token_package_hash: &ContractPackageHash) -> BTreeMap<Key, u32> {
     let commissions = AuctionData::load_commissions(&token_id, &token_package_hash)
         .unwrap_or_revert_with(AuctionError::MissingCommissions);
-
-    let mut share_sum = 0;
-    for (key, value) in &commissions {
-        let mut split = key.split('_');
-        let _actor = split
-            .next()
-            .unwrap_or_revert_with(AuctionError::CommissionActorSplit);
-        let property = split
-            .next()
-            .unwrap_or_revert_with(AuctionError::CommissionPropertySplit);
-        match property {
-            "account" => {
-                utils::string_to_account_hash(value);
-            },
-            "rate" => {
-                share_sum += utils::string_to_u16(value);
-            }
-            _ => revert(AuctionError::InvalidcommissionProperty),
-        }
-    }
-    if share_sum > 1000 {
-        revert(AuctionError::CommissionTooManyShares)
-    }
+    commissions::to_keyed_commissions(commissions::parse_commissions(&commissions).unwrap_or_revert())
 }
 
 fn get_token() -> (Key, Key, String, ContractPackageHash) {
@@ -97,6 +79,71 @@ fn get_token() -> (Key, Key, String, ContractPackageHash) {
     return (token_owner, beneficiary_account, token_id, ContractPackageHash::from(token_contract_hash));
 }
 
+// The remainder after commissions may be split between several beneficiaries, the split must add up to 100%
+fn get_beneficiaries(beneficiary_account: Key) -> BTreeMap<Key, u32> {
+    match runtime::get_named_arg::<Option<BTreeMap<Key, u32>>>(keys::BENEFICIARIES) {
+        Some(beneficiaries) => {
+            let mut share_sum: u32 = 0;
+            for (beneficiary, share) in &beneficiaries {
+                commissions::CommissionRecipient::from_key(*beneficiary)
+                    .unwrap_or_revert_with(AuctionError::InvalidBeneficiary);
+                if *share == 0 {
+                    runtime::revert(AuctionError::InvalidBeneficiarySplit)
+                }
+                share_sum = share_sum
+                    .checked_add(*share)
+                    .unwrap_or_revert_with(AuctionError::InvalidBeneficiarySplit);
+            }
+            if share_sum != utils::BASIS_POINTS {
+                runtime::revert(AuctionError::InvalidBeneficiarySplit)
+            }
+            beneficiaries
+        }
+        None => {
+            let mut beneficiaries = BTreeMap::new();
+            beneficiaries.insert(beneficiary_account, utils::BASIS_POINTS);
+            beneficiaries
+        }
+    }
+}
+
+// Marketplace account and highest commission an auction may charge, both set by the admin on chain.
+// The factory passes its own settings, installers read them from the factory named by their arguments.
+pub type MarketplaceSettings = (AccountHash, u32);
+
+fn get_marketplace_data(settings: MarketplaceSettings) -> (AccountHash, u32) {
+    let (settings_account, max_commission) = settings;
+    // The fee always goes to the marketplace, naming it is optional
+    let marketplace_account = match runtime::get_named_arg::<Option<Key>>(keys::MARKETPLACE_ACCOUNT) {
+        Some(Key::Account(account)) if account == settings_account => account,
+        Some(_) => runtime::revert(AuctionError::InvalidMarketplaceAccount),
+        None => settings_account,
+    };
+    let marketplace_commission = runtime::get_named_arg::<Option<u32>>(keys::MARKETPLACE_COMMISSION)
+        .unwrap_or_else(|| accounts::MARKETPLACE_COMMISSION.min(max_commission));
+    if marketplace_commission == 0 || marketplace_commission > max_commission {
+        runtime::revert(AuctionError::InvalidMarketplaceCommission)
+    }
+    (marketplace_account, marketplace_commission)
+}
+
+// Pending settlements can be rejected by anyone once this much time passed, None leaves it to the admin
+fn get_settlement_timeout() -> Option<u64> {
+    runtime::get_named_arg::<Option<u64>>(keys::SETTLEMENT_TIMEOUT)
+}
+
+// Increment rates in basis points of the winning bid, each band applying from its lower bound up to the next one.
+// The table has to start at zero so every price has a rate.
+fn get_minimum_bid_increments() -> Option<BTreeMap<U512, u32>> {
+    let increments = runtime::get_named_arg::<Option<BTreeMap<U512, u32>>>(keys::MINIMUM_BID_INCREMENTS)?;
+    if !increments.contains_key(&U512::zero())
+        || increments.values().any(|rate| *rate == 0 || *rate > utils::BASIS_POINTS)
+    {
+        runtime::revert(AuctionError::InvalidBidIncrements)
+    }
+    Some(increments)
+}
+
 fn get_proxy_contracts() -> (Option<ContractPackageHash>, Option<ContractPackageHash>) {
     let kyc_package_hash = match runtime::get_named_arg::<Key>(keys::KYC_PACKAGE_HASH)
         .into_hash() {
@@ -111,14 +158,35 @@ fn get_proxy_contracts() -> (Option<ContractPackageHash>, Option<ContractPackage
     return (kyc_package_hash, synth_package_hash);
 }
 
-pub fn create_english_auction_named_keys(marketplace_account: AccountHash, marketplace_commission: u32) -> NamedKeys {
+// Bidder gating, defaults to the KYC contract when one is given. Without one the mode has to be chosen,
+// so a missing or malformed KYC hash never opens bidding. The winner is only checked again at settlement if asked for.
+fn get_kyc_settings(kyc_package_hash: Option<ContractPackageHash>) -> (KycMode, Option<U256>, bool) {
+    let kyc_mode = match runtime::get_named_arg::<Option<u8>>(keys::KYC_MODE) {
+        Some(mode) => KycMode::from_u8(mode).unwrap_or_revert_with(AuctionError::InvalidKycMode),
+        None if kyc_package_hash.is_some() => KycMode::External,
+        None => runtime::revert(AuctionError::InvalidKycMode),
+    };
+    if kyc_mode == KycMode::External && kyc_package_hash.is_none() {
+        runtime::revert(AuctionError::InvalidKycMode)
+    }
+    let kyc_level = runtime::get_named_arg::<Option<U256>>(keys::KYC_LEVEL);
+    let kyc_recheck = runtime::get_named_arg::<Option<bool>>(keys::KYC_RECHECK).unwrap_or_default();
+    (kyc_mode, kyc_level, kyc_recheck)
+}
+
+pub fn create_english_auction_named_keys(marketplace_settings: MarketplaceSettings) -> NamedKeys {
     // Get the token info
     let (token_owner, beneficiary_account, token_id, token_package_hash) = get_token();
-    // Validate the commission structure in the NFT
-    validate_commissions(&token_id, &token_package_hash);
+    // Validate and snapshot the commission structure in the NFT
+    let commissions = snapshot_commissions(&token_id, &token_package_hash);
+    let beneficiaries = get_beneficiaries(beneficiary_account);
+    let recipient_purses = commissions::resolve_purses(commissions.keys().chain(beneficiaries.keys()));
 
     // Get the proxy contracts
     let (kyc_package_hash, synth_package_hash) = get_proxy_contracts();
+    let (kyc_mode, kyc_level, kyc_recheck) = get_kyc_settings(kyc_package_hash);
+    // Get the marketplace fee for this auction
+    let (marketplace_account, marketplace_commission) = get_marketplace_data(marketplace_settings);
 
     // Prices
     let reserve_price = runtime::get_named_arg::<U512>(keys::RESERVE_PRICE);
@@ -131,7 +199,7 @@ pub fn create_english_auction_named_keys(marketplace_account: AccountHash, marke
     // Starting state
     let winning_bid: Option<U512> = None;
     let current_winner: Option<Key> = None;
-    let status = 0_u8;
+    let status = AuctionStatus::Live;
 
     // Auction properties
     let bidder_count_cap = runtime::get_named_arg::<Option<u64>>(keys::BIDDER_NUMBER_CAP)
@@ -139,15 +207,26 @@ pub fn create_english_auction_named_keys(marketplace_account: AccountHash, marke
     let auction_timer_extension = runtime::get_named_arg::<Option<u64>>(keys::AUCTION_TIMER_EXTENSION)
         .unwrap_or_else(|| 5 * 60 * 1000);
     let minimum_bid_step = runtime::get_named_arg::<Option<U512>>(keys::MINIMUM_BID_STEP);
+    let minimum_bid_increments = get_minimum_bid_increments();
+    // Seller cancellation policy - penalty in basis points of the highest bid, None disables it
+    let seller_cancellation_penalty = runtime::get_named_arg::<Option<u32>>(keys::SELLER_CANCELLATION_PENALTY);
+    if seller_cancellation_penalty.map_or(false, |penalty| penalty > utils::BASIS_POINTS) {
+        runtime::revert(AuctionError::InvalidSellerCancellationPenalty);
+    }
 
-    let mut named_keys = named_keys!(
-        (keys::CONTRACT_TYPE, 2_u8),
+    named_keys!(
+        (keys::CONTRACT_TYPE, ContractType::EnglishAuction),
         (keys::OWNER, token_owner),
         (keys::BENEFICIARY_ACCOUNT, beneficiary_account),
+        (keys::BENEFICIARIES, beneficiaries),
         (keys::TOKEN_PACKAGE_HASH, token_package_hash),
         (keys::KYC_PACKAGE_HASH, kyc_package_hash),
+        (keys::KYC_MODE, kyc_mode),
+        (keys::KYC_LEVEL, kyc_level),
+        (keys::KYC_RECHECK, kyc_recheck),
         (keys::SYNTHETIC_PACKAGE_HASH, synth_package_hash),
         (keys::TOKEN_ID, token_id),
+        (keys::COMMISSIONS, commissions),
         (keys::START, start_time),
         (keys::CANCEL, cancellation_time),
         (keys::END, end_time),
@@ -159,21 +238,35 @@ pub fn create_english_auction_named_keys(marketplace_account: AccountHash, marke
         (keys::BIDDER_NUMBER_CAP, bidder_count_cap),
         (keys::AUCTION_TIMER_EXTENSION, auction_timer_extension),
         (keys::MINIMUM_BID_STEP, minimum_bid_step),
+        (keys::MINIMUM_BID_INCREMENTS, minimum_bid_increments),
+        (keys::SELLER_CANCELLATION_PENALTY, seller_cancellation_penalty),
         (keys::MARKETPLACE_COMMISSION, marketplace_commission),
-        (keys::MARKETPLACE_ACCOUNT, marketplace_account)
-    );
-    utils::add_empty_dict(&mut named_keys, keys::EVENTS);
-    named_keys
+        (keys::MARKETPLACE_ACCOUNT, marketplace_account),
+        (keys::RECIPIENT_PURSES, recipient_purses),
+        (keys::SETTLEMENT_PAYOUTS, Vec::<(Key, U512)>::new()),
+        (keys::SETTLEMENT_PROGRESS, 0_u32),
+        (keys::SETTLEMENT_STATUS, status),
+        (keys::SETTLEMENT_TIMEOUT, get_settlement_timeout()),
+        (keys::SETTLEMENT_DEADLINE, Option::<u64>::None),
+        (keys::PAUSED_AT, Option::<u64>::None),
+        (keys::STATE_VERSION, migrations::STATE_VERSION),
+        (keys::FACTORY, Option::<ContractPackageHash>::None)
+    )
 }
 
-pub fn create_dutch_auction_named_keys(marketplace_account: AccountHash, marketplace_commission: u32) -> NamedKeys {
+pub fn create_dutch_auction_named_keys(marketplace_settings: MarketplaceSettings) -> NamedKeys {
     // Get the token info
     let (token_owner, beneficiary_account, token_id, token_package_hash) = get_token();
-    // Validate the commission structure in the NFT
-    validate_commissions(&token_id, &token_package_hash);
+    // Validate and snapshot the commission structure in the NFT
+    let commissions = snapshot_commissions(&token_id, &token_package_hash);
+    let beneficiaries = get_beneficiaries(beneficiary_account);
+    let recipient_purses = commissions::resolve_purses(commissions.keys().chain(beneficiaries.keys()));
 
     // Get the proxy contracts
     let (kyc_package_hash, synth_package_hash) = get_proxy_contracts();
+    let (kyc_mode, kyc_level, kyc_recheck) = get_kyc_settings(kyc_package_hash);
+    // Get the marketplace fee for this auction
+    let (marketplace_account, marketplace_commission) = get_marketplace_data(marketplace_settings);
 
     // Prices
     let start_price = runtime::get_named_arg::<U512>(keys::START_PRICE);
@@ -190,16 +283,21 @@ pub fn create_dutch_auction_named_keys(marketplace_account: AccountHash, marketp
     // Starting state
     let winning_bid: Option<U512> = None;
     let current_winner: Option<Key> = None;
-    let status = 0_u8;
+    let status = AuctionStatus::Live;
 
-    let mut named_keys = named_keys!(
-        (keys::CONTRACT_TYPE, 3_u8),
+    named_keys!(
+        (keys::CONTRACT_TYPE, ContractType::DutchAuction),
         (keys::OWNER, token_owner),
         (keys::BENEFICIARY_ACCOUNT, beneficiary_account),
+        (keys::BENEFICIARIES, beneficiaries),
         (keys::TOKEN_PACKAGE_HASH, token_package_hash),
         (keys::KYC_PACKAGE_HASH, kyc_package_hash),
+        (keys::KYC_MODE, kyc_mode),
+        (keys::KYC_LEVEL, kyc_level),
+        (keys::KYC_RECHECK, kyc_recheck),
         (keys::SYNTHETIC_PACKAGE_HASH, synth_package_hash),
         (keys::TOKEN_ID, token_id),
+        (keys::COMMISSIONS, commissions),
         (keys::START, start_time),
         (keys::END, end_time),
         (keys::START_PRICE, start_price),
@@ -209,19 +307,31 @@ pub fn create_dutch_auction_named_keys(marketplace_account: AccountHash, marketp
         (keys::STATUS, status),
         (keys::EVENTS_COUNT, 0_u32),
         (keys::MARKETPLACE_COMMISSION, marketplace_commission),
-        (keys::MARKETPLACE_ACCOUNT, marketplace_account)
-    );
-    utils::add_empty_dict(&mut named_keys, keys::EVENTS);
-    named_keys
+        (keys::MARKETPLACE_ACCOUNT, marketplace_account),
+        (keys::RECIPIENT_PURSES, recipient_purses),
+        (keys::SETTLEMENT_PAYOUTS, Vec::<(Key, U512)>::new()),
+        (keys::SETTLEMENT_PROGRESS, 0_u32),
+        (keys::SETTLEMENT_STATUS, status),
+        (keys::SETTLEMENT_TIMEOUT, get_settlement_timeout()),
+        (keys::SETTLEMENT_DEADLINE, Option::<u64>::None),
+        (keys::PAUSED_AT, Option::<u64>::None),
+        (keys::STATE_VERSION, migrations::STATE_VERSION),
+        (keys::FACTORY, Option::<ContractPackageHash>::None)
+    )
 }
 
-pub fn create_swap_named_keys(marketplace_account: AccountHash, marketplace_commission: u32) -> NamedKeys {
+pub fn create_swap_named_keys(marketplace_settings: MarketplaceSettings) -> NamedKeys {
     // Get the token info
     let (token_owner, beneficiary_account, token_id, token_package_hash) = get_token();
-    // Validate the commission structure in the NFT
-    validate_commissions(&token_id, &token_package_hash);
+    // Validate and snapshot the commission structure in the NFT
+    let commissions = snapshot_commissions(&token_id, &token_package_hash);
+    let beneficiaries = get_beneficiaries(beneficiary_account);
+    let recipient_purses = commissions::resolve_purses(commissions.keys().chain(beneficiaries.keys()));
     // Get the proxy contracts
     let (kyc_package_hash, synth_package_hash) = get_proxy_contracts();
+    let (kyc_mode, kyc_level, kyc_recheck) = get_kyc_settings(kyc_package_hash);
+    // Get the marketplace fee for this auction
+    let (marketplace_account, marketplace_commission) = get_marketplace_data(marketplace_settings);
 
     // Prices
     let swap_price = runtime::get_named_arg::<U512>(keys::SWAP_PRICE);
@@ -234,16 +344,21 @@ pub fn create_swap_named_keys(marketplace_account: AccountHash, marketplace_comm
     // Starting state
     let winning_bid: Option<U512> = None;
     let current_winner: Option<Key> = None;
-    let status = 0_u8;
+    let status = AuctionStatus::Live;
 
-    let mut named_keys = named_keys!(
-        (keys::CONTRACT_TYPE, 4_u8),
+    named_keys!(
+        (keys::CONTRACT_TYPE, ContractType::Swap),
         (keys::OWNER, token_owner),
         (keys::BENEFICIARY_ACCOUNT, beneficiary_account),
+        (keys::BENEFICIARIES, beneficiaries),
         (keys::TOKEN_PACKAGE_HASH, token_package_hash),
         (keys::KYC_PACKAGE_HASH, kyc_package_hash),
+        (keys::KYC_MODE, kyc_mode),
+        (keys::KYC_LEVEL, kyc_level),
+        (keys::KYC_RECHECK, kyc_recheck),
         (keys::SYNTHETIC_PACKAGE_HASH, synth_package_hash),
         (keys::TOKEN_ID, token_id),
+        (keys::COMMISSIONS, commissions),
         (keys::START, start_time),
         (keys::END, end_time),
         (keys::SWAP_PRICE, swap_price),
@@ -252,22 +367,29 @@ pub fn create_swap_named_keys(marketplace_account: AccountHash, marketplace_comm
         (keys::STATUS, status),
         (keys::EVENTS_COUNT, 0_u32),
         (keys::MARKETPLACE_COMMISSION, marketplace_commission),
-        (keys::MARKETPLACE_ACCOUNT, marketplace_account)
-    );
-    utils::add_empty_dict(&mut named_keys, keys::EVENTS);
-    named_keys
+        (keys::MARKETPLACE_ACCOUNT, marketplace_account),
+        (keys::RECIPIENT_PURSES, recipient_purses),
+        (keys::SETTLEMENT_PAYOUTS, Vec::<(Key, U512)>::new()),
+        (keys::SETTLEMENT_PROGRESS, 0_u32),
+        (keys::SETTLEMENT_STATUS, status),
+        (keys::SETTLEMENT_TIMEOUT, get_settlement_timeout()),
+        (keys::SETTLEMENT_DEADLINE, Option::<u64>::None),
+        (keys::PAUSED_AT, Option::<u64>::None),
+        (keys::STATE_VERSION, migrations::STATE_VERSION),
+        (keys::FACTORY, Option::<ContractPackageHash>::None)
+    )
 }
 
 pub fn create_gift_named_keys(contract_name: String) -> NamedKeys {
-    let mut named_keys = named_keys!(
-        (keys::CONTRACT_TYPE, 5_u8),
+    named_keys!(
+        (keys::CONTRACT_TYPE, ContractType::Gift),
         (keys::TOKEN_COUNT, 0_u32),
         (keys::EVENTS_COUNT, 0_u32),
+        (keys::PAUSED_AT, Option::<u64>::None),
+        (keys::STATE_VERSION, migrations::STATE_VERSION),
+        (keys::FACTORY, Option::<ContractPackageHash>::None),
         (keys::NAME, contract_name)
-    );
-    utils::add_empty_dict(&mut named_keys, keys::TOKENS);
-    utils::add_empty_dict(&mut named_keys, keys::EVENTS);
-    named_keys
+    )
 }
 
 
@@ -0,0 +1,174 @@
+use alloc::{format, string::String, vec::Vec};
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args, CLType, CLTyped, ContractPackageHash, Key, RuntimeArgs, U512,
+};
+
+use crate::data::AuctionData;
+use crate::error::AuctionError;
+use crate::{functions, keys, utils, Dict};
+
+/// Synthetic credit of a bidder across every auction of the factory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreditLine {
+    // No limit only tracks the exposure
+    pub limit: Option<U512>,
+    // Held by standing synthetic bids
+    pub reserved: U512,
+    // Owed for approved synthetic wins until repaid
+    pub settled: U512,
+}
+
+impl CreditLine {
+    pub fn exposure(&self) -> U512 {
+        self.reserved + self.settled
+    }
+}
+
+impl CLTyped for CreditLine {
+    fn cl_type() -> CLType {
+        <(Option<U512>, U512, U512)>::cl_type()
+    }
+}
+
+impl ToBytes for CreditLine {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.limit.to_bytes()?);
+        result.extend(self.reserved.to_bytes()?);
+        result.extend(self.settled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.limit.serialized_length() + self.reserved.serialized_length() + self.settled.serialized_length()
+    }
+}
+
+impl FromBytes for CreditLine {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (limit, remainder) = Option::<U512>::from_bytes(bytes)?;
+        let (reserved, remainder) = U512::from_bytes(remainder)?;
+        let (settled, remainder) = U512::from_bytes(remainder)?;
+        Ok((CreditLine { limit, reserved, settled }, remainder))
+    }
+}
+
+/**
+ * Credit ledger kept by the factory. Every synthetic bid placed in one of its auctions holds credit
+ * until the bid is released, or turned into a settled obligation when the win is approved.
+ * An auction holds at most one amount per bidder, a higher bid replaces the hold.
+ */
+pub struct CreditLedger {
+    lines: Dict,
+    holds: Dict,
+}
+
+impl CreditLedger {
+    // Creates the ledger dictionaries, to be used only once when installing the factory.
+    pub fn init(named_keys: &mut NamedKeys) {
+        utils::add_empty_dict(named_keys, keys::FACTORY_CREDIT_LINES);
+        utils::add_empty_dict(named_keys, keys::FACTORY_CREDIT_HOLDS);
+    }
+
+    pub fn at() -> CreditLedger {
+        CreditLedger {
+            lines: Dict::at(keys::FACTORY_CREDIT_LINES),
+            holds: Dict::at(keys::FACTORY_CREDIT_HOLDS),
+        }
+    }
+
+    pub fn line(&self, account: Key) -> CreditLine {
+        self.lines._get(&account.to_formatted_string()).unwrap_or_default()
+    }
+
+    pub fn set_limit(&self, account: Key, limit: Option<U512>) {
+        let mut line = self.line(account);
+        line.limit = limit;
+        self.lines.set(&account.to_formatted_string(), line);
+    }
+
+    pub fn reserve(&self, auction: ContractPackageHash, account: Key, amount: U512) {
+        let hold = hold_key(auction, account);
+        let previous: U512 = self.holds._get(&hold).unwrap_or_default();
+        let mut line = self.line(account);
+        line.reserved = line.reserved - previous + amount;
+        if line.limit.map_or(false, |limit| line.exposure() > limit) {
+            runtime::revert(AuctionError::CreditLimitExceeded)
+        }
+        self.holds.set(&hold, amount);
+        self.lines.set(&account.to_formatted_string(), line);
+    }
+
+    pub fn release(&self, auction: ContractPackageHash, account: Key) {
+        if let Some(amount) = self.take_hold(auction, account) {
+            let mut line = self.line(account);
+            line.reserved -= amount;
+            self.lines.set(&account.to_formatted_string(), line);
+        }
+    }
+
+    pub fn settle(&self, auction: ContractPackageHash, account: Key) {
+        if let Some(amount) = self.take_hold(auction, account) {
+            let mut line = self.line(account);
+            line.reserved -= amount;
+            line.settled += amount;
+            self.lines.set(&account.to_formatted_string(), line);
+        }
+    }
+
+    pub fn repay(&self, account: Key, amount: U512) {
+        let mut line = self.line(account);
+        if amount > line.settled {
+            runtime::revert(AuctionError::InvalidCreditRepayment)
+        }
+        line.settled -= amount;
+        self.lines.set(&account.to_formatted_string(), line);
+    }
+
+    fn take_hold(&self, auction: ContractPackageHash, account: Key) -> Option<U512> {
+        let hold = hold_key(auction, account);
+        let amount = self.holds._get::<U512>(&hold)?;
+        self.holds._remove::<U512>(&hold);
+        Some(amount)
+    }
+}
+
+fn hold_key(auction: ContractPackageHash, account: Key) -> String {
+    format!("{}_{}", auction.to_formatted_string(), account.to_formatted_string())
+}
+
+/**
+ * Side of the ledger in the auction. Auctions created by the factory report their synthetic bids to it,
+ * installed auctions have no ledger and only ask the synthetic contract.
+ */
+pub struct Credit;
+
+impl Credit {
+    pub fn reserve(bidder: Key, amount: U512) {
+        Self::call(functions::RESERVE_CREDIT, runtime_args! {
+            keys::BIDDER => bidder,
+            keys::AMOUNT => amount,
+        });
+    }
+
+    pub fn release(bidder: Key) {
+        Self::call(functions::RELEASE_CREDIT, runtime_args! {
+            keys::BIDDER => bidder,
+        });
+    }
+
+    pub fn settle(bidder: Key) {
+        Self::call(functions::SETTLE_CREDIT, runtime_args! {
+            keys::BIDDER => bidder,
+        });
+    }
+
+    fn call(entry_point: &str, args: RuntimeArgs) {
+        if let Some(factory) = AuctionData::factory() {
+            runtime::call_versioned_contract::<()>(factory, None, entry_point, args);
+        }
+    }
+}
@@ -1,31 +1,22 @@
 use casper_contract::{
-    contract_api::{
-        runtime::{self, revert},
-    },
+    contract_api::runtime,
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{runtime_args, ContractPackageHash, RuntimeArgs, URef};
 
-use crate::{bids::Bids, error::AuctionError, keys};
+use crate::{bids::Bids, error::AuctionError, functions, keys};
+use crate::kyc::Kyc;
+use crate::utils::share_of;
+use crate::status::{AuctionStatus, ContractType};
 use alloc::{
-    collections::{BTreeMap, BTreeSet},
-    format,
+    collections::BTreeMap,
     string::{String, ToString},
+    vec::Vec,
 };
 use casper_types::{account::AccountHash, Key, U512};
 use casper_types::system::CallStackElement;
 use crate::keys::{CURRENT_WINNER, read_named_key_uref, read_named_key_value, WINNING_BID, write_named_key_value};
-use crate::utils::{string_to_account_hash, string_to_u16};
-
-// Auction status
-pub const AUCTION_LIVE: u8 = 0;
-pub const AUCTION_CANCELLED: u8 = 1;
-pub const AUCTION_PENDING_SETTLE: u8 = 2;
-pub const AUCTION_REJECTED: u8 = 3;
-pub const AUCTION_SETTLED: u8 = 4;
-
-const ACCOUNT_TAG: &str = "account";
-const RATE_TAG: &str = "rate";
+use crate::commissions::{from_keyed_commissions, parse_commissions, to_keyed_commissions, CommissionRecipient};
 
 pub struct AuctionData;
 
@@ -42,7 +33,7 @@ impl AuctionData {
         read_named_key_value::<String>(keys::TOKEN_ID)
     }
 
-    pub fn update_current_winner(winner: Option<AccountHash>, bid: Option<U512>, synthetic: bool) {
+    pub fn update_current_winner(winner: Option<Key>, bid: Option<U512>, synthetic: bool) {
         write_named_key_value(CURRENT_WINNER, winner);
         if bid.is_some() {
             write_named_key_value(WINNING_BID, Some((bid.unwrap(), synthetic)));
@@ -64,24 +55,60 @@ impl AuctionData {
     }
 
     pub fn is_done() -> bool {
-        read_named_key_value::<u8>(keys::STATUS) > AUCTION_LIVE
+        Self::status().is_done()
     }
 
-    pub fn status() -> u8 {
-        read_named_key_value::<u8>(keys::STATUS)
+    pub fn status() -> AuctionStatus {
+        read_named_key_value::<AuctionStatus>(keys::STATUS)
     }
 
-    pub fn contract_type() -> u8 {
-        read_named_key_value::<u8>(keys::CONTRACT_TYPE)
+    pub fn contract_type() -> ContractType {
+        read_named_key_value::<ContractType>(keys::CONTRACT_TYPE)
+    }
+
+    /**
+     * Revert unless the current status may move to the given one. Settling is entered only towards a target
+     * the table allows, which is stored first as the settlement status, and left only for that target.
+     */
+    pub fn check_transition(next: AuctionStatus) {
+        let current = Self::status();
+        let allowed = match (current, next) {
+            (_, AuctionStatus::Settling) => current.can_transition_to(Self::settlement_status()),
+            (AuctionStatus::Settling, _) => next == Self::settlement_status(),
+            _ => current.can_transition_to(next),
+        };
+        if !allowed {
+            runtime::revert(AuctionError::InvalidTransition)
+        }
     }
 
-    pub fn update_status(status: u8) {
+    // Same as `check_transition`, for a change only allowed out of the given status
+    pub fn check_transition_from(current: AuctionStatus, next: AuctionStatus) {
+        if Self::status() != current {
+            runtime::revert(AuctionError::InvalidTransition)
+        }
+        Self::check_transition(next)
+    }
+
+    // Every status change goes through here, and is reported to the factory that created the contract
+    pub fn update_status(status: AuctionStatus) {
+        Self::check_transition(status);
         write_named_key_value(keys::STATUS, status);
+        if let Some(factory) = Self::factory() {
+            runtime::call_versioned_contract::<()>(factory, None, functions::UPDATE_STATUS, runtime_args! {
+                keys::STATUS => status,
+            });
+        }
     }
 
-    pub fn current_winner() -> (Option<AccountHash>, Option<(U512, bool)>) {
+    // Package of the factory that created this contract, if any
+    pub fn factory() -> Option<ContractPackageHash> {
+        read_named_key_value(keys::FACTORY)
+    }
+
+    pub fn current_winner() -> (Option<Key>, Option<(U512, bool)>) {
         (
-            read_named_key_value::<Option<AccountHash>>(keys::CURRENT_WINNER),
+            read_named_key_value::<Option<Key>>(keys::CURRENT_WINNER),
             read_named_key_value::<Option<(U512, bool)>>(keys::WINNING_BID)
         )
     }
@@ -135,6 +162,66 @@ impl AuctionData {
             .unwrap_or_revert_with(AuctionError::KeyNotAccount)
     }
 
+    // Split of the proceeds left after commissions, rates are in basis points
+    pub fn beneficiaries() -> BTreeMap<CommissionRecipient, u32> {
+        from_keyed_commissions(read_named_key_value(keys::BENEFICIARIES))
+    }
+
+    // Purses the contract recipients of commissions, beneficiary shares and redirected payouts are paid into
+    pub fn recipient_purses() -> BTreeMap<Key, URef> {
+        read_named_key_value(keys::RECIPIENT_PURSES)
+    }
+
+    pub fn add_recipient_purses(purses: BTreeMap<Key, URef>) {
+        let mut recipient_purses = Self::recipient_purses();
+        recipient_purses.extend(purses);
+        write_named_key_value(keys::RECIPIENT_PURSES, recipient_purses);
+    }
+
+    // Payouts of the current settlement, in the order they are paid
+    pub fn settlement_payouts() -> Vec<(Key, U512)> {
+        read_named_key_value(keys::SETTLEMENT_PAYOUTS)
+    }
+
+    pub fn set_settlement_payouts(payouts: Vec<(Key, U512)>) {
+        write_named_key_value(keys::SETTLEMENT_PAYOUTS, payouts);
+    }
+
+    // Number of settlement payouts already paid
+    pub fn settlement_progress() -> u32 {
+        read_named_key_value(keys::SETTLEMENT_PROGRESS)
+    }
+
+    pub fn set_settlement_progress(progress: u32) {
+        write_named_key_value(keys::SETTLEMENT_PROGRESS, progress);
+    }
+
+    // Status the auction ends in once the settlement is done
+    pub fn settlement_status() -> AuctionStatus {
+        read_named_key_value(keys::SETTLEMENT_STATUS)
+    }
+
+    pub fn set_settlement_status(status: AuctionStatus) {
+        write_named_key_value(keys::SETTLEMENT_STATUS, status);
+    }
+
+    // Time a synthetic winner has to settle before anyone may reject it
+    pub fn settlement_timeout() -> Option<u64> {
+        read_named_key_value(keys::SETTLEMENT_TIMEOUT)
+    }
+
+    pub fn settlement_deadline() -> Option<u64> {
+        read_named_key_value(keys::SETTLEMENT_DEADLINE)
+    }
+
+    pub fn set_settlement_deadline(deadline: Option<u64>) {
+        write_named_key_value(keys::SETTLEMENT_DEADLINE, deadline);
+    }
+
+    pub fn is_settlement_overdue() -> bool {
+        Self::settlement_deadline().map_or(false, |deadline| u64::from(runtime::get_blocktime()) >= deadline)
+    }
+
     pub fn is_auction_live() -> bool {
         // Check that it's not too late and that the auction isn't finalized
         let start_time = Self::start_time();
@@ -150,14 +237,6 @@ impl AuctionData {
         block_time < end_time && block_time >= start_time
     }
 
-    // pub fn set_commissions(commissions: BTreeMap<String, String>) {
-    //     write_named_key_value(COMMISSIONS, commissions);
-    // }
-
-    // pub fn get_commissions() -> BTreeMap<String, String> {
-    //     read_named_key_value(COMMISSIONS)
-    // }
-
     pub fn bidder_count_cap() -> Option<u64> {
         read_named_key_value(keys::BIDDER_NUMBER_CAP)
     }
@@ -166,6 +245,32 @@ impl AuctionData {
         read_named_key_value(keys::MINIMUM_BID_STEP)
     }
 
+    // Increment rates in basis points of the winning bid, by the lower bound of their price band
+    pub fn minimum_bid_increments() -> Option<BTreeMap<U512, u32>> {
+        read_named_key_value(keys::MINIMUM_BID_INCREMENTS)
+    }
+
+    // Smallest raise over a winning bid: the step, or the rate of its price band if that is more, and at least a mote
+    pub fn minimum_increment(winning_bid: U512) -> U512 {
+        let step = Self::minimum_bid_step().unwrap_or_default();
+        let relative = Self::minimum_bid_increments()
+            .and_then(|bands| bands.range(..=winning_bid).next_back().map(|(_, rate)| *rate))
+            .map_or_else(U512::zero, |rate| share_of(winning_bid, rate));
+        core::cmp::max(core::cmp::max(step, relative), U512::one())
+    }
+
+    // Lowest bid the auction accepts next
+    pub fn min_next_bid() -> U512 {
+        match Self::current_winner().1 {
+            Some((winning_bid, _)) => winning_bid + Self::minimum_increment(winning_bid),
+            None => Self::reserve_price(),
+        }
+    }
+
+    pub fn seller_cancellation_penalty() -> Option<u32> {
+        read_named_key_value(keys::SELLER_CANCELLATION_PENALTY)
+    }
+
     pub fn marketplace_data() -> (AccountHash, u32) {
         (
             read_named_key_value(keys::MARKETPLACE_ACCOUNT),
@@ -184,53 +289,22 @@ impl AuctionData {
         )
     }
 
-    pub fn compute_commissions() -> BTreeMap<AccountHash, u16> {
+    // Loads and parses the current commission table of the token, rates are in basis points
+    pub fn fetch_commissions() -> BTreeMap<CommissionRecipient, u32> {
         let token_id = Self::token_id();
         let token_package_hash = Self::token_package_hash();
         let commissions = Self::load_commissions(&token_id, &token_package_hash)
             .unwrap_or_revert_with(AuctionError::MissingCommissions);
+        parse_commissions(&commissions).unwrap_or_revert()
+    }
 
-        let mut converted_commissions: BTreeMap<AccountHash, u16> = BTreeMap::new();
-        let mut done: BTreeSet<String> = BTreeSet::new();
-        let mut share_sum = 0;
-        for (key, value) in &commissions {
-            let mut split = key.split('_');
-            let actor = split
-                .next()
-                .unwrap_or_revert_with(AuctionError::CommissionActorSplit)
-                .to_string();
-            if done.contains(&actor) {
-                continue;
-            }
-            let property = split
-                .next()
-                .unwrap_or_revert_with(AuctionError::CommissionPropertySplit)
-                .to_string();
-            match property.as_ref() {
-                ACCOUNT_TAG => {
-                    let rate = commissions
-                        .get(&format!("{}_rate", actor))
-                        .unwrap_or_revert_with(AuctionError::MismatchedCommissionAccount);
-                    let share_rate = string_to_u16(rate);
-                    share_sum += share_rate;
-                    converted_commissions.insert(string_to_account_hash(value), share_rate);
-                }
-                RATE_TAG => {
-                    let account = commissions
-                        .get(&format!("{}_account", actor))
-                        .unwrap_or_revert_with(AuctionError::MismatchedCommissionRate);
-                    let share_rate = string_to_u16(value);
-                    share_sum += share_rate;
-                    converted_commissions.insert(string_to_account_hash(account), share_rate);
-                }
-                _ => revert(AuctionError::InvalidcommissionProperty),
-            }
-            done.insert(actor.to_string());
-        }
-        if share_sum > 1000 {
-            revert(AuctionError::CommissionTooManyShares)
-        }
-        converted_commissions
+    // Commission table frozen when the auction was created, rates are in basis points
+    pub fn compute_commissions() -> BTreeMap<CommissionRecipient, u32> {
+        from_keyed_commissions(read_named_key_value(keys::COMMISSIONS))
+    }
+
+    pub fn set_commissions(commissions: BTreeMap<CommissionRecipient, u32>) {
+        write_named_key_value(keys::COMMISSIONS, to_keyed_commissions(commissions));
     }
 
     pub fn kyc_package_hash() -> Option<ContractPackageHash> {
@@ -242,18 +316,7 @@ impl AuctionData {
     }
 
     pub fn is_verified(account: &Key) -> bool {
-        let contract_package_hash = Self::kyc_package_hash()
-            .unwrap_or_revert_with(AuctionError::KYCError);
-        runtime::call_versioned_contract::<bool>(
-            contract_package_hash,
-            None,
-            "is_kyc_proved",
-            runtime_args! {
-                // "account" => Key::Account(runtime::get_caller()),
-                "account" => account.clone(),
-                "index" => Option::<casper_types::U256>::None
-            },
-        )
+        Kyc::is_verified(account)
     }
 
     pub fn is_allowed(account: &Key, amount: &U512) -> bool {
@@ -298,6 +361,23 @@ impl AuctionData {
         }
     }
 
+    /**
+     * Push the rest of the schedule back: the end, the English cancellation time, and the start of a Dutch auction,
+     * whose price curve thus picks up where it was instead of dropping by the delay.
+     */
+    pub fn delay_times(delay: u64) {
+        write_named_key_value(keys::END, Self::end_time() + delay);
+        match Self::contract_type() {
+            ContractType::EnglishAuction => {
+                if let Some(cancel) = Self::cancel_time() {
+                    write_named_key_value(keys::CANCEL, Some(cancel + delay));
+                }
+            }
+            ContractType::DutchAuction => write_named_key_value(keys::START, Self::start_time() + delay),
+            _ => (),
+        }
+    }
+
     pub fn current_caller() -> Key {
         Key::Account(runtime::get_caller())
     }
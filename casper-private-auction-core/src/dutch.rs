@@ -1,19 +1,15 @@
-use casper_contract::{
-    contract_api::runtime,
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::contract_api::runtime;
 pub use casper_types::{
     ApiError, bytesrepr::FromBytes, CLTyped, ContractHash, contracts::NamedKeys,
     Key, runtime_args, RuntimeArgs, system::CallStackElement, U512, URef,
 };
 pub use casper_types::bytesrepr::ToBytes;
 
-use crate::{
-    data::AuctionData,
-    events::{AuctionEvent, emit},
-};
+use crate::data::AuctionData;
+use crate::agents::BiddingAgents;
 use crate::auction::Auction;
-use crate::data::{AUCTION_CANCELLED, AUCTION_PENDING_SETTLE, AUCTION_SETTLED};
+use crate::credit::Credit;
+use crate::status::AuctionStatus;
 use crate::error::AuctionError;
 
 pub struct DutchAuction;
@@ -22,15 +18,14 @@ impl DutchAuction {
     /**
      * Place bid on the auction - if valid, wins the auction
      */
-    pub fn bid(account: Key, bid: U512, bidder_purse: Option<URef>) {
+    pub fn bid(bidder: Key, bid: U512, bidder_purse: Option<URef>) {
         // Get computed current price
         let current_price = AuctionData::current_price();
         if bid < current_price {
             runtime::revert(AuctionError::BidTooLow);
         }
 
-        let bidder = account.into_account()
-            .unwrap_or_revert_with(AuctionError::KeyNotAccount);
+        BiddingAgents::check_bidder(&bidder);
 
         // Save the bid
         let mut bids = AuctionData::bids();
@@ -44,13 +39,11 @@ impl DutchAuction {
             Auction::move_bid_funds(bidder_purse, bid.clone());
             // Settle the auction
             // TODO: can this be optimized to settle from this purse directly?
-            Auction::settle(Some(bidder.into()));
-            AuctionData::update_status(AUCTION_SETTLED);
-            emit(&AuctionEvent::Settled { account: Some(bidder), bid: Some((current_price, false)) })
+            Auction::settle(Some(bidder), AuctionStatus::Settled);
         } else {
             // Cannot settle auction, however put it into pending settle
-            AuctionData::update_status(AUCTION_PENDING_SETTLE);
-            emit(&AuctionEvent::PendingSettlement { account: bidder, bid: (current_price, true) })
+            Credit::reserve(bidder, bid);
+            Auction::await_settlement(bidder, (current_price, true))
         }
     }
 
@@ -58,9 +51,7 @@ impl DutchAuction {
      * Cancel the auction
      */
     pub fn cancel() {
-        Auction::settle(None);
-        AuctionData::update_status(AUCTION_CANCELLED);
-        emit(&AuctionEvent::Cancelled { })
+        Auction::settle(None, AuctionStatus::Cancelled);
     }
 }
 
@@ -5,7 +5,6 @@ use casper_contract::{
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::account::AccountHash;
 pub use casper_types::bytesrepr::ToBytes;
 pub use casper_types::{
     bytesrepr::FromBytes, contracts::NamedKeys, runtime_args, system::CallStackElement, ApiError,
@@ -17,15 +16,21 @@ use crate::{
     data::AuctionData,
     events::{emit, AuctionEvent},
 };
+use crate::agents::BiddingAgents;
 use crate::auction::Auction;
-use crate::data::{AUCTION_CANCELLED, AUCTION_PENDING_SETTLE, AUCTION_SETTLED};
+use crate::credit::Credit;
+use crate::pause::Pause;
+use crate::proxy::ProxyBids;
+use crate::utils::share_of;
+use alloc::collections::BTreeMap;
+use crate::status::AuctionStatus;
 
 pub struct EnglishAuction;
 
 impl EnglishAuction {
 
-    // Add a bid to the bid set
-    fn add_bid(bidder: &AccountHash, bidder_purse: Option<URef>, new_bid: &U512) {
+    // Add a bid to the bid set. A funded bid escrows `escrow` (at least the bid), the excess is its proxy maximum.
+    fn add_bid(bidder: &Key, bidder_purse: Option<URef>, new_bid: &U512, escrow: U512) {
         let auction_purse = AuctionData::auction_purse();
         // Check the purse is good
         if !auction_purse.is_addable() {
@@ -44,31 +49,45 @@ impl EnglishAuction {
                         bids.remove_by_key(&lowest_bidder);
                         // If the bid was not synthetic, then return it
                         if !lowest_bid.1 {
-                            system::transfer_from_purse_to_account(
-                                auction_purse,
-                                lowest_bidder,
-                                lowest_bid.0,
-                                None,
-                            )
-                                .unwrap_or_revert_with(AuctionError::BidReturnLowest);
+                            let refund = ProxyBids::take_escrow(&lowest_bidder, lowest_bid.0);
+                            BiddingAgents::refund(auction_purse, lowest_bidder, refund, AuctionError::BidReturnLowest);
+                        } else {
+                            Credit::release(lowest_bidder);
                         }
                     }
                 }
             }
         }
-        // Compute the new bid amount
-        let bid_amount = if let Some(current_bid) = existing_bidder {
-            if *new_bid <= current_bid.0 {
+        // Compute the amount to transfer, only a funded bid has its escrow in the auction purse already
+        let current_escrow = match existing_bidder {
+            Some((current_amount, false)) => ProxyBids::escrow(bidder, current_amount),
+            _ => U512::zero(),
+        };
+        if let Some(current_bid) = existing_bidder {
+            // Raising only the maximum keeps the bid
+            if *new_bid < current_bid.0 || (*new_bid == current_bid.0 && escrow <= current_escrow) {
                 runtime::revert(AuctionError::NewBidLower)
             }
-            *new_bid - current_bid.0
-        } else {
-            *new_bid
-        };
+        }
         let synthetic = bidder_purse.is_none();
         if !synthetic {
-            system::transfer_from_purse_to_purse(bidder_purse.unwrap(), auction_purse, bid_amount, None)
+            system::transfer_from_purse_to_purse(bidder_purse.unwrap(), auction_purse, escrow - current_escrow, None)
                 .unwrap_or_revert_with(AuctionError::TransferBidToAuction);
+            if escrow > *new_bid {
+                ProxyBids::set(bidder, escrow);
+            } else {
+                ProxyBids::clear(bidder);
+            }
+        } else if !current_escrow.is_zero() {
+            // A funded bid raised synthetically gets its funds back
+            ProxyBids::clear(bidder);
+            BiddingAgents::refund(auction_purse, *bidder, current_escrow, AuctionError::AuctionCancelReturnBid);
+        }
+        // A synthetic bid holds credit for its whole amount
+        if synthetic {
+            Credit::reserve(*bidder, *new_bid);
+        } else if existing_bidder.map_or(false, |bid| bid.1) {
+            Credit::release(*bidder);
         }
 
         if existing_bidder.is_none() {
@@ -78,11 +97,42 @@ impl EnglishAuction {
         }
     }
 
+    // Raise the bid of a proxy bidder from its escrow, without new funds
+    fn auto_bid(bidder: Key, bid: U512) {
+        AuctionData::bids().replace(&bidder, bid, false);
+        if ProxyBids::max(&bidder).map_or(false, |max| max <= bid) {
+            ProxyBids::clear(&bidder);
+        }
+    }
+
+    /**
+     * Turn a synthetic bid into a funded one or the other way round, keeping its amount and place in the bids
+     */
+    fn convert_bid(bidder: Key, amount: U512, bidder_purse: Option<URef>) {
+        let synthetic = bidder_purse.is_none();
+        if synthetic {
+            // The funds go back, the bid holds credit instead
+            let refund = ProxyBids::take_escrow(&bidder, amount);
+            BiddingAgents::refund(AuctionData::auction_purse(), bidder, refund, AuctionError::AuctionCancelReturnBid);
+            Credit::reserve(bidder, amount);
+        } else {
+            Auction::move_bid_funds(bidder_purse, amount);
+            Credit::release(bidder);
+        }
+        AuctionData::bids().replace(&bidder, amount, synthetic);
+        if AuctionData::current_winner().0 == Some(bidder) {
+            AuctionData::update_current_winner(Some(bidder), Some(amount), synthetic);
+        }
+
+        emit(&AuctionEvent::BidConverted { account: bidder, bid: amount, synthetic })
+    }
+
     /**
      * Specialised check before cancellation
      */
     pub fn check_valid() {
-        Auction::check_valid();
+        // Bids can still be withdrawn while the auction is paused
+        Auction::check_live();
 
         // In addition - check we are within the cancel time
         let cancel_time = AuctionData::cancel_time();
@@ -94,55 +144,102 @@ impl EnglishAuction {
     }
 
     /**
-     * Place bid on the auction - if valid, becomes the new best price in the auction
+     * Place bid on the auction - if valid, becomes the new best price in the auction.
+     * A proxy bid escrows `bid` as the bidder's maximum: the visible bid is one increment above the highest
+     * competing maximum, and it is raised automatically as competitors bid, up to the maximum.
      */
-    pub fn bid(account: Key, bid: U512, bidder_purse: Option<URef>) {
-        let bidder = account.into_account().unwrap_or_revert_with(AuctionError::KeyNotAccount);
+    pub fn bid(bidder: Key, bid: U512, bidder_purse: Option<URef>, proxy: bool) {
+        BiddingAgents::check_bidder(&bidder);
+        let synthetic = bidder_purse.is_none();
+        if proxy && synthetic {
+            runtime::revert(AuctionError::ProxyBidNotFunded)
+        }
+
+        // Switching how a standing bid is funded keeps its amount and its place
+        if let Some(current_bid) = AuctionData::bids().get(&bidder) {
+            if !proxy && current_bid.0 == bid && current_bid.1 != synthetic {
+                return Self::convert_bid(bidder, bid, bidder_purse);
+            }
+        }
 
         if bid < AuctionData::reserve_price() {
             runtime::revert(AuctionError::BidBelowReserve);
         }
-        let (_, winning_bid) = AuctionData::current_winner();
-        // If there is a winning bid, ensure this bid is above that, else reject early..
-        if winning_bid.is_some() {
-            let (wp, _) = winning_bid.unwrap();
-            if bid <= wp {
-                runtime::revert(AuctionError::BidTooLow);
-            } else {
-                let min_step = AuctionData::minimum_bid_step().unwrap_or_default();
-                let bid_delta = bid - wp;
-                if bid_delta < min_step {
+        match AuctionData::current_winner() {
+            // The leader raises its maximum, the visible bid stays
+            (Some(leader), Some((wp, _))) if leader == bidder && proxy => {
+                if bid <= ProxyBids::escrow(&bidder, wp) {
                     runtime::revert(AuctionError::BidTooLow);
                 }
+                Self::add_bid(&bidder, bidder_purse, &wp, bid);
+                AuctionData::update_current_winner(Some(bidder), Some(wp), false);
+            }
+            (Some(leader), Some((wp, leader_synthetic))) => {
+                // Ensure this bid is at least one increment above the winning one, else reject early..
+                if bid < wp + AuctionData::minimum_increment(wp) {
+                    runtime::revert(AuctionError::BidTooLow);
+                }
+                let leader_max = if leader_synthetic { wp } else { ProxyBids::escrow(&leader, wp) };
+                if leader == bidder {
+                    // The leader raises its bid, a higher maximum stays escrowed
+                    let escrow = core::cmp::max(bid, leader_max);
+                    Self::add_bid(&bidder, bidder_purse, &bid, escrow);
+                    AuctionData::update_current_winner(Some(bidder), Some(bid), synthetic);
+                    emit(&AuctionEvent::Bid { account: bidder, bid, synthetic });
+                } else if bid > leader_max {
+                    // The leader's proxy bids its whole maximum, this bid tops it
+                    if leader_synthetic {
+                        // A displaced synthetic leader no longer holds credit
+                        Credit::release(leader);
+                    } else if leader_max > wp {
+                        Self::auto_bid(leader, leader_max);
+                        emit(&AuctionEvent::AutoBid { account: leader, bid: leader_max });
+                    }
+                    let visible = if proxy { core::cmp::min(bid, leader_max + AuctionData::minimum_increment(leader_max)) } else { bid };
+                    Self::add_bid(&bidder, bidder_purse, &visible, bid);
+                    AuctionData::update_current_winner(Some(bidder), Some(visible), synthetic);
+                    emit(&AuctionEvent::Bid { account: bidder, bid: visible, synthetic });
+                } else if bid == leader_max {
+                    // An equal maximum loses to the earlier one
+                    runtime::revert(AuctionError::BidTooLow);
+                } else {
+                    // The leader's proxy outbids this bid, raised first so the bidder cap keeps it
+                    let raised = core::cmp::min(leader_max, bid + AuctionData::minimum_increment(bid));
+                    Self::auto_bid(leader, raised);
+                    Self::add_bid(&bidder, bidder_purse, &bid, bid);
+                    AuctionData::update_current_winner(Some(leader), Some(raised), false);
+                    emit(&AuctionEvent::Bid { account: bidder, bid, synthetic });
+                    emit(&AuctionEvent::AutoBid { account: leader, bid: raised });
+                }
+            }
+            _ => {
+                // The first proxy bid opens at the reserve price
+                let visible = if proxy { AuctionData::reserve_price() } else { bid };
+                Self::add_bid(&bidder, bidder_purse, &visible, bid);
+                AuctionData::update_current_winner(Some(bidder), Some(visible), synthetic);
+                emit(&AuctionEvent::Bid { account: bidder, bid: visible, synthetic });
             }
         }
-        let synthetic = bidder_purse.is_none();
-        // Save the bid
-        Self::add_bid(&bidder, bidder_purse, &bid);
-        // Update the current winner
-        AuctionData::update_current_winner(Some(bidder), Some(bid), synthetic);
         // See if we need to extend the auction
         AuctionData::extend_auction();
-
-        emit(&AuctionEvent::Bid { account: bidder, bid, synthetic })
     }
 
     /**
      * Cancel a bid
      */
-    pub fn cancel_bid(account: Key) {
+    pub fn cancel_bid(bidder: Key) {
         let mut bids = AuctionData::bids();
-        let bidder = account.into_account().unwrap_or_revert_with(AuctionError::KeyNotAccount);
         match bids.get(&bidder) {
             Some(current_bid) => {
                 if !current_bid.1 {
-                    system::transfer_from_purse_to_account(
+                    BiddingAgents::refund(
                         AuctionData::auction_purse(),
-                        bidder.into(),
-                        current_bid.0,
-                        None,
-                    )
-                        .unwrap_or_revert_with(AuctionError::AuctionCancelReturnBid);
+                        bidder,
+                        ProxyBids::take_escrow(&bidder, current_bid.0),
+                        AuctionError::AuctionCancelReturnBid,
+                    );
+                } else {
+                    Credit::release(bidder);
                 }
                 bids.remove_by_key(&bidder);
                 let (new_winner, new_bid, new_synth) = bids.max_by_key();
@@ -158,33 +255,31 @@ impl EnglishAuction {
      * Finalize the auction if possible - only callable by owner
      */
     pub fn finalize(time_check: bool) {
-        // Get finalization and check if we're done
-        if AuctionData::is_done() {
-            runtime::revert(AuctionError::AuctionCompleted)
-        };
+        Pause::check();
+
+        // Finalizing either settles or awaits approval, both only from a live auction
+        AuctionData::check_transition(AuctionStatus::Settled);
 
         // Cannot finalize before the end of the auction
         if time_check && u64::from(runtime::get_blocktime()) < AuctionData::end_time() {
             runtime::revert(AuctionError::EarlyFinalize)
         }
 
+        // Drop a winner that no longer passes the gating, if the auction asks for it
+        Auction::recheck_winner();
+
         // See if there is a winner
         match AuctionData::current_winner() {
             (Some(bidder), Some(bid)) => {
                 // Synthetic bid - put it in pending settle state
                 if bid.1 {
-                    AuctionData::update_status(AUCTION_PENDING_SETTLE);
-                    emit(&AuctionEvent::PendingSettlement { account: bidder, bid })
+                    Auction::await_settlement(bidder, bid)
                 } else {
-                    Auction::settle(Some(bidder.into()));
-                    AuctionData::update_status(AUCTION_SETTLED);
-                    emit(&AuctionEvent::Settled { account: Some(bidder), bid: Some(bid) })
+                    Auction::settle(Some(bidder), AuctionStatus::Settled)
                 }
             }
             _ => {
-                Auction::settle(None);
-                AuctionData::update_status(AUCTION_SETTLED);
-                emit(&AuctionEvent::Settled { account: None, bid: None });
+                Auction::settle(None, AuctionStatus::Settled);
             }
         };
     }
@@ -196,13 +291,77 @@ impl EnglishAuction {
         // If we have a current winner, then this auction cannot be cancelled
         let (winner, winning_bid) = AuctionData::current_winner();
         if winner.is_none() && winning_bid.is_none() {
-            Auction::settle(None);
-            AuctionData::update_status(AUCTION_CANCELLED);
-            emit(&AuctionEvent::Cancelled { });
+            Auction::settle(None, AuctionStatus::Cancelled);
             return
         }
         runtime::revert(AuctionError::CannotCancelAuction);
     }
+
+    /**
+     * Seller cancellation once bids exist, if the auction allows it. The seller deposits a penalty
+     * (a rate of the highest bid) that is shared between the bidders in proportion to their bids,
+     * the rounding dust going to the highest bidder. The bids themselves are refunded as usual.
+     */
+    pub fn cancel_with_penalty(seller_purse: URef) {
+        let penalty_rate = AuctionData::seller_cancellation_penalty()
+            .unwrap_or_revert_with(AuctionError::SellerCancellationDisabled);
+
+        let highest_bid = match AuctionData::current_winner() {
+            (Some(_), Some((highest_bid, _))) => highest_bid,
+            // Nobody to compensate, the regular cancellation applies
+            _ => return Self::cancel(),
+        };
+
+        // Only funded bids are compensated, synthetic bidders put no funds at stake
+        let funded_bids: BTreeMap<Key, U512> = AuctionData::bids()
+            .to_map()
+            .into_iter()
+            .filter(|(_, bid)| !bid.1)
+            .map(|(bidder, bid)| (bidder, bid.0))
+            .collect();
+        let penalty = match funded_bids.is_empty() {
+            true => U512::zero(),
+            false => share_of(highest_bid, penalty_rate),
+        };
+        let total_bids = funded_bids.values().fold(U512::zero(), |total, bid| total + *bid);
+        let mut compensations: BTreeMap<Key, U512> = BTreeMap::new();
+        let mut given = U512::zero();
+        for (bidder, bid) in &funded_bids {
+            let compensation = penalty * *bid / total_bids;
+            given += compensation;
+            compensations.insert(*bidder, compensation);
+        }
+        // The rounding dust goes to the highest funded bid
+        if let Some((top_bidder, _)) = funded_bids.iter().max_by_key(|(_, bid)| **bid) {
+            *compensations.entry(*top_bidder).or_insert_with(U512::zero) += penalty - given;
+        }
+
+        for (bidder, compensation) in compensations {
+            if compensation.is_zero() {
+                continue;
+            }
+            BiddingAgents::refund(seller_purse, bidder, compensation, AuctionError::TransferPenalty);
+        }
+
+        AuctionData::update_current_winner(None, None, false);
+        emit(&AuctionEvent::CancelledWithPenalty { penalty });
+        Auction::settle(None, AuctionStatus::Cancelled);
+    }
+
+    /**
+     * Void the auction even if there are bids - only callable by admin.
+     * Every funded bid is refunded and the token goes back to the owner.
+     */
+    pub fn force_cancel(reason: u8) {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        AuctionData::check_transition(AuctionStatus::Voided);
+
+        AuctionData::update_current_winner(None, None, false);
+        Auction::settle(None, AuctionStatus::Voided);
+        emit(&AuctionEvent::Voided { reason });
+    }
 }
 
 
@@ -0,0 +1,395 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use casper_types::{
+    account::AccountHash, CLType, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPoints, EntryPointType, Key, Parameter,
+};
+
+use crate::info::AuctionInfo;
+use crate::{functions, keys};
+
+// Entry points of an English auction contract
+pub fn english() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::BID,
+        vec![
+            Parameter::new(keys::BID, CLType::U512),
+            Parameter::new(keys::BID_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::PROXY_BID,
+        vec![
+            Parameter::new(keys::MAX_BID, CLType::U512),
+            Parameter::new(keys::BID_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SYNTHETIC_BID,
+        vec![
+            Parameter::new(keys::BID, CLType::U512),
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_BID,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_MIN_NEXT_BID,
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_SYNTHETIC_BID,
+        vec![
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_AUCTION,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_WITH_PENALTY,
+        vec![
+            Parameter::new(keys::PENALTY_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::FORCE_CANCEL,
+        vec![
+            Parameter::new(keys::REASON, CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::FINALIZE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    add_auction_entry_points(&mut entry_points);
+
+    entry_points
+}
+
+// Entry points of a Dutch auction contract
+pub fn dutch() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::BID,
+        vec![
+            Parameter::new(keys::BID, CLType::U512),
+            Parameter::new(keys::BID_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::SYNTHETIC_BID,
+        vec![
+            Parameter::new(keys::BID, CLType::U512),
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_SYNTHETIC_BID,
+        vec![
+            Parameter::new(keys::BIDDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL_AUCTION,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    add_auction_entry_points(&mut entry_points);
+
+    entry_points
+}
+
+// Entry points every auction format shares, their handlers are in `handlers::auction`
+fn add_auction_entry_points(entry_points: &mut EntryPoints) {
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::APPROVE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::REJECT,
+        vec![
+            Parameter::new(keys::OFFER_NEXT, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::FUND_SYNTHETIC,
+        vec![
+            Parameter::new(keys::BID_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::REFRESH_COMMISSIONS,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::RESUME_SETTLEMENT,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::REDIRECT_PAYOUT,
+        vec![
+            Parameter::new(keys::HOLDING_ACCOUNT, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UPDATE_GATING_LIST,
+        vec![
+            Parameter::new(keys::ACCOUNTS, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(keys::LISTED, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UPDATE_BIDDING_AGENTS,
+        vec![
+            Parameter::new(keys::AGENTS, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(keys::APPROVED, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::PAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UNPAUSE,
+        vec![
+            Parameter::new(keys::EXTEND_END_TIME, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_BID,
+        vec![],
+        CLType::Option(Box::new(CLType::U512)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_FEES,
+        vec![],
+        <(AccountHash, u32)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_AUCTION_INFO,
+        vec![],
+        AuctionInfo::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::MIGRATE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::INIT,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+}
+
+// Swaps expose the same entry points as Dutch auctions
+pub fn swap() -> EntryPoints {
+    dutch()
+}
+
+// Entry points of a gift contract
+pub fn gift() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CLAIM,
+        vec![
+            Parameter::new(keys::RECEIVER, CLType::Key),
+            Parameter::new(keys::TOKEN_ID, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::CANCEL,
+        vec![
+            Parameter::new(keys::SENDER, CLType::Key),
+            Parameter::new(keys::TOKEN_ID, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GIFT,
+        vec![
+            Parameter::new(keys::SENDER, CLType::Key),
+            Parameter::new(keys::TOKEN_ID, CLType::String),
+            Parameter::new(keys::TOKEN_PACKAGE_HASH, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::PAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::UNPAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::MIGRATE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_GIFT_COUNT,
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_GIFTS,
+        vec![
+            Parameter::new(keys::OFFSET, CLType::U32),
+            Parameter::new(keys::LIMIT, CLType::U32),
+        ],
+        Vec::<(String, Key, ContractPackageHash)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::INIT,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points
+}
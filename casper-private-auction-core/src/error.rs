@@ -62,6 +62,32 @@ pub enum AuctionError {
     SyntheticBidNotAllowed = 58,
     ContractPackageNotFound = 59,
     TokenNotFound = 60,
+    InvalidMarketplaceAccount = 61,
+    InvalidMarketplaceCommission = 62,
+    CommissionContractIncorrectSerialization = 63,
+    CommissionPublicKeyIncorrectSerialization = 64,
+    CommissionRateTooPrecise = 65,
+    InvalidBeneficiarySplit = 66,
+    InvalidHoldingAccount = 67,
+    ContractPaused = 68,
+    ContractNotPaused = 69,
+    SellerCancellationDisabled = 70,
+    InvalidSellerCancellationPenalty = 71,
+    TransferPenalty = 72,
+    StateVersionTooNew = 73,
+    AuctionNotRegistered = 74,
+    InvalidTransition = 75,
+    InvalidKycMode = 76,
+    RefundDisqualifiedBid = 77,
+    CreditLimitExceeded = 78,
+    InvalidCreditRepayment = 79,
+    InvalidBiddingAgent = 80,
+    RefundPurseNotAddable = 81,
+    ProxyBidNotFunded = 82,
+    InvalidBidIncrements = 83,
+    RecipientPurseNotAddable = 84,
+    AlreadyInstalled = 85,
+    NotInstalled = 86,
 }
 
 impl From<AuctionError> for ApiError {
@@ -1,6 +1,8 @@
 use crate::error::AuctionError;
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
 use casper_contract::contract_api::runtime::{self, revert};
 use casper_contract::contract_api::storage;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
@@ -10,26 +12,75 @@ use crate::keys::{EVENTS, EVENTS_COUNT};
 
 pub enum AuctionEvent {
     Bid {
-        account: AccountHash,
+        account: Key,
         bid: U512,
         synthetic: bool,
     },
     BidCancelled {
-        account: AccountHash,
+        account: Key,
+    },
+    BidConverted {
+        account: Key,
+        bid: U512,
+        synthetic: bool,
+    },
+    AutoBid {
+        account: Key,
+        bid: U512,
     },
     Cancelled {
     },
     PendingSettlement {
-        account: AccountHash,
+        account: Key,
         bid: (U512, bool),
     },
     SettlementRejected {
-        account: Option<AccountHash>,
+        account: Option<Key>,
+    },
+    WinnerDisqualified {
+        account: Key,
+        bid: (U512, bool),
     },
     Settled {
-        account: Option<AccountHash>,
+        account: Option<Key>,
         bid: Option<(U512, bool)>,
     },
+    SettlementReceipt {
+        account: Key,
+        bid: U512,
+        payouts: Vec<(Key, U512)>,
+        skipped: Vec<Key>,
+    },
+    CommissionsRefreshed {
+    },
+    GatingListUpdated {
+        accounts: u32,
+        listed: bool,
+    },
+    BiddingAgentsUpdated {
+        agents: u32,
+        approved: bool,
+    },
+    SettlementStalled {
+        account: Key,
+        amount: U512,
+    },
+    PayoutRedirected {
+        account: Key,
+        holding_account: Key,
+        amount: U512,
+    },
+    Paused {
+    },
+    Unpaused {
+        duration: u64,
+    },
+    Voided {
+        reason: u8,
+    },
+    CancelledWithPenalty {
+        penalty: U512,
+    },
     Gifted {
         account: AccountHash,
         token_id: String,
@@ -48,7 +99,7 @@ pub fn emit(event: &AuctionEvent) {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
             event.insert("event_id", event_id.clone());
-            event.insert("account", account.to_string());
+            event.insert("account", account.to_formatted_string());
             event.insert("event_type", "Bid".to_string());
             event.insert("bid", bid.to_string());
             event.insert("synthetic", synthetic.to_string());
@@ -58,10 +109,29 @@ pub fn emit(event: &AuctionEvent) {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
             event.insert("event_id", event_id.clone());
-            event.insert("account", account.to_string());
+            event.insert("account", account.to_formatted_string());
             event.insert("event_type", "BidCancelled".to_string());
             (event, event_id)
         }
+        AuctionEvent::BidConverted { account, bid, synthetic } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("bid", bid.to_string());
+            event.insert("synthetic", synthetic.to_string());
+            event.insert("event_type", "BidConverted".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::AutoBid { account, bid } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("bid", bid.to_string());
+            event.insert("event_type", "AutoBid".to_string());
+            (event, event_id)
+        }
         AuctionEvent::Cancelled { } => {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
@@ -73,7 +143,7 @@ pub fn emit(event: &AuctionEvent) {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
             event.insert("event_id", event_id.clone());
-            event.insert("account", account.to_string());
+            event.insert("account", account.to_formatted_string());
             event.insert("bid", bid.0.to_string());
             event.insert("synthetic", bid.1.to_string());
             event.insert("event_type", "PendingSettlement".to_string());
@@ -84,17 +154,27 @@ pub fn emit(event: &AuctionEvent) {
             let event_id = events_count.to_string();
             event.insert("event_id", event_id.clone());
             if account.is_some() {
-                event.insert("account", account.unwrap().to_string());
+                event.insert("account", account.unwrap().to_formatted_string());
             }
             event.insert("event_type", "SettlementRejected".to_string());
             (event, event_id)
         }
+        AuctionEvent::WinnerDisqualified { account, bid } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("bid", bid.0.to_string());
+            event.insert("synthetic", bid.1.to_string());
+            event.insert("event_type", "WinnerDisqualified".to_string());
+            (event, event_id)
+        }
         AuctionEvent::Settled { account, bid } => {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
             event.insert("event_id", event_id.clone());
             if account.is_some() {
-                event.insert("account", account.unwrap().to_string());
+                event.insert("account", account.unwrap().to_formatted_string());
             }
             if bid.is_some() {
                 let wb = bid.unwrap();
@@ -104,6 +184,97 @@ pub fn emit(event: &AuctionEvent) {
             event.insert("event_type", "Settled".to_string());
             (event, event_id)
         }
+        AuctionEvent::SettlementReceipt { account, bid, payouts, skipped } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("bid", bid.to_string());
+            let payouts: Vec<String> = payouts
+                .iter()
+                .map(|(recipient, amount)| format!("{}:{}", recipient.to_formatted_string(), amount))
+                .collect();
+            event.insert("payouts", payouts.join(","));
+            let skipped: Vec<String> = skipped.iter().map(|recipient| recipient.to_formatted_string()).collect();
+            event.insert("skipped", skipped.join(","));
+            event.insert("event_type", "SettlementReceipt".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::CommissionsRefreshed { } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("event_type", "CommissionsRefreshed".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::GatingListUpdated { accounts, listed } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("accounts", accounts.to_string());
+            event.insert("listed", listed.to_string());
+            event.insert("event_type", "GatingListUpdated".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::BiddingAgentsUpdated { agents, approved } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("agents", agents.to_string());
+            event.insert("approved", approved.to_string());
+            event.insert("event_type", "BiddingAgentsUpdated".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::SettlementStalled { account, amount } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("amount", amount.to_string());
+            event.insert("event_type", "SettlementStalled".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::PayoutRedirected { account, holding_account, amount } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("account", account.to_formatted_string());
+            event.insert("holding_account", holding_account.to_formatted_string());
+            event.insert("amount", amount.to_string());
+            event.insert("event_type", "PayoutRedirected".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::Paused { } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("event_type", "Paused".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::Unpaused { duration } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("duration", duration.to_string());
+            event.insert("event_type", "Unpaused".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::Voided { reason } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("reason", reason.to_string());
+            event.insert("event_type", "Voided".to_string());
+            (event, event_id)
+        }
+        AuctionEvent::CancelledWithPenalty { penalty } => {
+            let mut event = BTreeMap::new();
+            let event_id = events_count.to_string();
+            event.insert("event_id", event_id.clone());
+            event.insert("penalty", penalty.to_string());
+            event.insert("event_type", "CancelledWithPenalty".to_string());
+            (event, event_id)
+        }
         AuctionEvent::Gifted { account, token_id } => {
             let mut event = BTreeMap::new();
             let event_id = events_count.to_string();
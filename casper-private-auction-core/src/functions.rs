@@ -1,16 +1,54 @@
 pub const INIT: &str = "init";
 pub const BID: &str = "bid";
 pub const SYNTHETIC_BID: &str = "synthetic_bid";
+pub const PROXY_BID: &str = "proxy_bid";
 pub const CANCEL_BID: &str = "cancel_bid";
 pub const CANCEL_SYNTHETIC_BID: &str = "cancel_synthetic_bid";
 pub const FINALIZE: &str = "finalize";
 pub const CANCEL_AUCTION: &str = "cancel_auction";
 pub const APPROVE: &str = "approve";
 pub const REJECT: &str = "reject";
+pub const FUND_SYNTHETIC: &str = "fund_synthetic";
+pub const REFRESH_COMMISSIONS: &str = "refresh_commissions";
+pub const RESUME_SETTLEMENT: &str = "resume_settlement";
+pub const REDIRECT_PAYOUT: &str = "redirect_payout";
+pub const PAUSE: &str = "pause";
+pub const UNPAUSE: &str = "unpause";
+pub const FORCE_CANCEL: &str = "force_cancel";
+pub const UPDATE_GATING_LIST: &str = "update_gating_list";
+pub const UPDATE_BIDDING_AGENTS: &str = "update_bidding_agents";
+pub const CANCEL_WITH_PENALTY: &str = "cancel_with_penalty";
+pub const MIGRATE: &str = "migrate";
+pub const CREATE_ENGLISH_AUCTION: &str = "create_english_auction";
+pub const CREATE_DUTCH_AUCTION: &str = "create_dutch_auction";
+pub const CREATE_SWAP: &str = "create_swap";
+pub const CREATE_GIFT: &str = "create_gift";
+pub const SYNC_STATUS: &str = "sync_status";
+pub const UPDATE_STATUS: &str = "update_status";
+pub const UPGRADE_AUCTION: &str = "upgrade_auction";
+pub const GET_AUCTION_COUNT: &str = "get_auction_count";
+pub const GET_AUCTIONS: &str = "get_auctions";
+pub const GET_AUCTIONS_BY_SELLER: &str = "get_auctions_by_seller";
+pub const GET_AUCTIONS_BY_STATUS: &str = "get_auctions_by_status";
+pub const GET_AUCTIONS_BY_TOKEN: &str = "get_auctions_by_token";
+pub const RESERVE_CREDIT: &str = "reserve_credit";
+pub const RELEASE_CREDIT: &str = "release_credit";
+pub const SETTLE_CREDIT: &str = "settle_credit";
+pub const SET_CREDIT_LIMIT: &str = "set_credit_limit";
+pub const REPAY_CREDIT: &str = "repay_credit";
+pub const GET_CREDIT: &str = "get_credit";
+pub const SET_MARKETPLACE_SETTINGS: &str = "set_marketplace_settings";
+pub const GET_MARKETPLACE_SETTINGS: &str = "get_marketplace_settings";
 pub const GET_BID: &str = "get_bid";
+pub const GET_MIN_NEXT_BID: &str = "get_min_next_bid";
+pub const GET_FEES: &str = "get_fees";
+pub const GET_AUCTION_INFO: &str = "get_auction_info";
+pub const GET_PURSE: &str = "get_purse";
 pub const GIFT: &str = "gift";
 pub const CLAIM: &str = "claim";
 pub const CANCEL: &str = "cancel";
+pub const GET_GIFT_COUNT: &str = "get_gift_count";
+pub const GET_GIFTS: &str = "get_gifts";
 
 
 
@@ -1,5 +1,5 @@
 use alloc::string::{String};
-use alloc::{vec};
+use alloc::{vec, vec::Vec};
 use casper_contract::contract_api::runtime;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 pub use casper_types::{
@@ -9,20 +9,23 @@ pub use casper_types::{
 pub use casper_types::bytesrepr::ToBytes;
 use casper_types::ContractPackageHash;
 
-use crate::{events::{AuctionEvent, emit}, keys};
+use crate::events::{AuctionEvent, emit};
 use crate::error::AuctionError;
+use crate::gifts::Gifts;
+use crate::pause::Pause;
 
 pub struct Gift;
 
 impl Gift {
 
     pub fn claim(receiver: Key, token_id: String) {
-        let gifts = crate::Dict::at(keys::TOKENS);
+        Pause::check();
+        let mut gifts = Gifts::at();
         // Get the token
-        let (_, package_hash) = gifts._get::<(Key, ContractPackageHash)>(token_id.as_str())
+        let (_, package_hash) = gifts.get(token_id.as_str())
             .unwrap_or_revert_with(AuctionError::TokenNotFound);
         // Remove this key
-        gifts._remove::<(Key, ContractPackageHash)>(token_id.as_str());
+        gifts.remove(token_id.as_str());
 
         // Transfer the token back to the owner
         let token_ids = vec![token_id.clone()];
@@ -40,12 +43,12 @@ impl Gift {
     }
 
     pub fn cancel(token_id: String) {
-        let gifts = crate::Dict::at(keys::TOKENS);
+        let mut gifts = Gifts::at();
         // Get the token
-        let (owner, package_hash) = gifts._get::<(Key, ContractPackageHash)>(token_id.as_str())
+        let (owner, package_hash) = gifts.get(token_id.as_str())
             .unwrap_or_revert_with(AuctionError::TokenNotFound);
         // Remove this key
-        gifts._remove::<(Key, ContractPackageHash)>(token_id.as_str());
+        gifts.remove(token_id.as_str());
 
         // Transfer the token back to the owner
         let token_ids = vec![token_id];
@@ -63,12 +66,21 @@ impl Gift {
     }
 
     pub fn gift(sender: Key, token_id: String, token_package_hash: ContractPackageHash) {
+        Pause::check();
         // Create the mapping in the dictionary
-        let gifts = crate::Dict::at(keys::TOKENS);
-        gifts.set(token_id.as_str(), (sender.clone(), token_package_hash.clone()));
+        let mut gifts = Gifts::at();
+        gifts.insert(token_id.as_str(), sender.clone(), token_package_hash.clone());
 
         emit(&AuctionEvent::Gifted { account: sender.into_account().unwrap(), token_id })
     }
+
+    pub fn count() -> u32 {
+        Gifts::at().len()
+    }
+
+    pub fn list(offset: u32, limit: u32) -> Vec<(String, Key, ContractPackageHash)> {
+        Gifts::at().page(offset, limit)
+    }
     //
     // fn get_gift_contract() -> Key {
     //     {
@@ -0,0 +1,96 @@
+use alloc::{string::{String, ToString}, vec::Vec};
+use casper_types::{ContractPackageHash, Key};
+
+use crate::keys::{self, read_named_key_value, write_named_key_value};
+use crate::Dict;
+
+pub struct Gifts {
+    tokens: Dict,
+    index: Dict,
+    token_ids: Dict,
+    len: u32,
+}
+
+impl Gifts {
+    // Fetches the gift escrow dictionaries and the current count.
+    pub fn at() -> Gifts {
+        Gifts {
+            tokens: Dict::at(keys::TOKENS),
+            index: Dict::at(keys::TOKENS_INDEX),
+            token_ids: Dict::at(keys::TOKENS_BY_INDEX),
+            len: read_named_key_value(keys::TOKEN_COUNT),
+        }
+    }
+
+    // If exists, returns the sender and token package stored under a token id.
+    pub fn get(&self, token_id: &str) -> Option<(Key, ContractPackageHash)> {
+        self.tokens._get(token_id)
+    }
+
+    // Get the token id corresponding to an index.
+    pub fn get_token_id_by_index(&self, index: u32) -> Option<String> {
+        self.token_ids._get(&index.to_string())
+    }
+
+    // Return the index a token id is stored under.
+    pub fn get_index_by_token_id(&self, token_id: &str) -> Option<u32> {
+        self.index._get(token_id)
+    }
+
+    // Adds a gift to the escrow. If the token id is already held, only the value is replaced.
+    pub fn insert(&mut self, token_id: &str, sender: Key, token_package_hash: ContractPackageHash) {
+        if self.get_index_by_token_id(token_id).is_none() {
+            self.insert_token_index(self.len, token_id);
+            self.set_len(self.len + 1);
+        }
+        self.tokens.set(token_id, (sender, token_package_hash));
+    }
+
+    // Remove all data under a token id. If the token is not the last one, the last token is moved to its index.
+    pub fn remove(&mut self, token_id: &str) {
+        if let Some(index) = self.get_index_by_token_id(token_id) {
+            let last = self.len - 1;
+            if index < last {
+                if let Some(last_token_id) = self.get_token_id_by_index(last) {
+                    self.insert_token_index(index, &last_token_id);
+                }
+            }
+            self.token_ids._remove::<String>(&last.to_string());
+            self.index._remove::<u32>(token_id);
+            self.set_len(last);
+        }
+        self.tokens._remove::<(Key, ContractPackageHash)>(token_id);
+    }
+
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns up to `limit` active gifts starting at `offset`.
+    pub fn page(&self, offset: u32, limit: u32) -> Vec<(String, Key, ContractPackageHash)> {
+        let mut ret = Vec::new();
+        let end = offset.saturating_add(limit).min(self.len);
+        for i in offset..end {
+            if let Some(token_id) = self.get_token_id_by_index(i) {
+                if let Some((sender, token_package_hash)) = self.get(&token_id) {
+                    ret.push((token_id, sender, token_package_hash));
+                }
+            }
+        }
+        ret
+    }
+
+    fn insert_token_index(&self, index: u32, token_id: &str) {
+        self.token_ids.set(&index.to_string(), token_id.to_string());
+        self.index.set(token_id, index);
+    }
+
+    fn set_len(&mut self, length: u32) {
+        write_named_key_value(keys::TOKEN_COUNT, length);
+        self.len = length;
+    }
+}
@@ -0,0 +1,441 @@
+//! Bodies of the contract entry points, shared by the installers and the factory.
+//! Every function reads its own named arguments, checks the caller and calls into the auction logic.
+
+// Entry points every auction format has
+pub mod auction {
+    use alloc::vec::Vec;
+    use casper_contract::{
+        contract_api::{runtime, system},
+        unwrap_or_revert::UnwrapOrRevert,
+    };
+    use casper_types::{CLValue, Key, URef};
+
+    use crate::auction::Auction;
+    use crate::bids::Bids;
+    use crate::data::AuctionData;
+    use crate::info::AuctionInfo;
+    use crate::status::ContractType;
+    use crate::{keys, migrations, utils};
+
+    pub fn approve() {
+        Auction::approve();
+    }
+
+    pub fn reject() {
+        let offer_next = runtime::get_named_arg::<bool>(keys::OFFER_NEXT);
+        Auction::reject(offer_next);
+    }
+
+    pub fn fund_synthetic() {
+        let funding_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+        Auction::fund_synthetic(funding_purse);
+    }
+
+    pub fn refresh_commissions() {
+        Auction::refresh_commissions();
+    }
+
+    pub fn resume_settlement() {
+        Auction::resume_settlement();
+    }
+
+    pub fn redirect_payout() {
+        let holding_account = runtime::get_named_arg::<Key>(keys::HOLDING_ACCOUNT);
+        Auction::redirect_payout(holding_account);
+    }
+
+    pub fn update_gating_list() {
+        let accounts = runtime::get_named_arg::<Vec<Key>>(keys::ACCOUNTS);
+        let listed = runtime::get_named_arg::<bool>(keys::LISTED);
+        Auction::update_gating_list(accounts, listed);
+    }
+
+    pub fn update_bidding_agents() {
+        let agents = runtime::get_named_arg::<Vec<Key>>(keys::AGENTS);
+        let approved = runtime::get_named_arg::<bool>(keys::APPROVED);
+        Auction::update_bidding_agents(agents, approved);
+    }
+
+    pub fn pause() {
+        Auction::pause();
+    }
+
+    pub fn unpause() {
+        let extend_end_time = runtime::get_named_arg::<bool>(keys::EXTEND_END_TIME);
+        Auction::unpause(extend_end_time);
+    }
+
+    pub fn get_bid() {
+        let bids = Bids::at();
+        let bid = bids.get(&AuctionData::current_bidder());
+        runtime::ret(CLValue::from_t(bid).unwrap_or_revert());
+    }
+
+    pub fn get_fees() {
+        runtime::ret(CLValue::from_t(AuctionData::marketplace_data()).unwrap_or_revert());
+    }
+
+    pub fn get_auction_info() {
+        runtime::ret(CLValue::from_t(AuctionInfo::load()).unwrap_or_revert());
+    }
+
+    pub fn migrate() {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+        migrations::migrate();
+    }
+
+    // Runs in the context of the new contract, so its purse and dictionaries land in its own named keys
+    pub fn init() {
+        if runtime::get_key(keys::AUCTION_PURSE).is_none() {
+            let purse = system::create_purse();
+            runtime::put_key(keys::AUCTION_PURSE, purse.into());
+            Bids::init();
+        }
+        utils::add_missing_dict(keys::EVENTS);
+        utils::add_missing_dict(keys::GATING_LIST);
+        utils::add_missing_dict(keys::BIDDING_AGENTS);
+        utils::add_missing_dict(keys::AGENT_BIDDERS);
+        if AuctionData::contract_type() == ContractType::EnglishAuction {
+            utils::add_missing_dict(keys::PROXY_BIDS);
+        }
+    }
+}
+
+pub mod english {
+    use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+    use casper_types::{CLValue, Key, URef, U512};
+
+    use crate::agents::BiddingAgents;
+    use crate::auction::Auction;
+    use crate::data::AuctionData;
+    use crate::english::EnglishAuction;
+    use crate::error::AuctionError;
+    use crate::keys;
+
+    pub fn bid() {
+        Auction::check_valid();
+
+        // Ensure the purse is configured correctly
+        let bidder_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+        if !bidder_purse.is_writeable() || !bidder_purse.is_readable() {
+            runtime::revert(AuctionError::BidderPurseBadPermission)
+        }
+
+        // Get the bidder from the stack, or from an approved agent
+        let account = BiddingAgents::bidder();
+        Auction::verify(&account);
+
+        // Place the bid
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+        EnglishAuction::bid(account, bid, Some(bidder_purse), false);
+    }
+
+    pub fn proxy_bid() {
+        Auction::check_valid();
+
+        // Ensure the purse is configured correctly
+        let bidder_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+        if !bidder_purse.is_writeable() || !bidder_purse.is_readable() {
+            runtime::revert(AuctionError::BidderPurseBadPermission)
+        }
+
+        // Get the bidder from the stack, or from an approved agent
+        let account = BiddingAgents::bidder();
+        Auction::verify(&account);
+
+        // Escrow the maximum, the auction bids up to it
+        let max_bid = runtime::get_named_arg::<U512>(keys::MAX_BID);
+        EnglishAuction::bid(account, max_bid, Some(bidder_purse), true);
+    }
+
+    pub fn synthetic_bid() {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        // Ensure auction is still valid
+        Auction::check_valid();
+
+        // Check the user is allowed to bid synthetically..
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+        Auction::synth_allowed(&account, &bid);
+
+        EnglishAuction::bid(account, bid, Option::<URef>::None, false);
+    }
+
+    pub fn cancel_bid() {
+        // This checks we are within cancel time
+        EnglishAuction::check_valid();
+
+        // Get the caller from the environment, or the bidder of an approved agent
+        let account = BiddingAgents::canceller();
+        Auction::verify(&account);
+
+        EnglishAuction::cancel_bid(account);
+    }
+
+    pub fn cancel_synthetic_bid() {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+        // This checks we are within cancel time
+        EnglishAuction::check_valid();
+
+        // All the details are passed in
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        Auction::synth_enabled(&account);
+
+        EnglishAuction::cancel_bid(account);
+    }
+
+    pub fn cancel_auction() {
+        Auction::check_valid();
+
+        // Only owner is allowed to cancel
+        Auction::check_owner();
+
+        EnglishAuction::cancel();
+    }
+
+    pub fn cancel_with_penalty() {
+        // The penalty is deposited via session code
+        if runtime::get_call_stack().len() != 2 {
+            runtime::revert(AuctionError::DisallowedMiddleware);
+        }
+
+        // Not paused, and within cancel time
+        Auction::check_valid();
+        EnglishAuction::check_valid();
+
+        // Only owner is allowed to cancel
+        Auction::check_owner();
+
+        let seller_purse = runtime::get_named_arg::<URef>(keys::PENALTY_PURSE);
+        if !seller_purse.is_writeable() || !seller_purse.is_readable() {
+            runtime::revert(AuctionError::BidderPurseBadPermission)
+        }
+
+        EnglishAuction::cancel_with_penalty(seller_purse);
+    }
+
+    pub fn force_cancel() {
+        let reason = runtime::get_named_arg::<u8>(keys::REASON);
+        EnglishAuction::force_cancel(reason);
+    }
+
+    pub fn finalize() {
+        // Only owner is allowed to finalize
+        Auction::check_owner();
+
+        EnglishAuction::finalize(true);
+    }
+
+    pub fn get_min_next_bid() {
+        runtime::ret(CLValue::from_t(AuctionData::min_next_bid()).unwrap_or_revert());
+    }
+}
+
+pub mod dutch {
+    use casper_contract::contract_api::runtime;
+    use casper_types::{Key, URef, U512};
+
+    use crate::agents::BiddingAgents;
+    use crate::auction::Auction;
+    use crate::dutch::DutchAuction;
+    use crate::keys;
+
+    pub fn bid() {
+        Auction::check_valid();
+        // Get the bidder from the stack, or from an approved agent
+        let account = BiddingAgents::bidder();
+        Auction::verify(&account);
+
+        // Only bid is passed in
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+        let bidder_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+
+        // Place the bid
+        DutchAuction::bid(account, bid, Some(bidder_purse));
+    }
+
+    pub fn synthetic_bid() {
+        Auction::check_valid();
+
+        // All the details are passed in
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+        Auction::synth_allowed(&account, &bid);
+
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        DutchAuction::bid(account, bid, Option::<URef>::None);
+    }
+
+    pub fn cancel_synthetic_bid() {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        // All the details are passed in
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        Auction::withdraw_synthetic(account);
+    }
+
+    pub fn cancel_auction() {
+        Auction::check_valid();
+
+        // Only owner is allowed to cancel
+        Auction::check_owner();
+
+        DutchAuction::cancel();
+    }
+}
+
+pub mod swap {
+    use casper_contract::contract_api::runtime;
+    use casper_types::{Key, URef, U512};
+
+    use crate::agents::BiddingAgents;
+    use crate::auction::Auction;
+    use crate::keys;
+    use crate::swap::Swap;
+
+    pub fn bid() {
+        Auction::check_valid();
+
+        // Get the bidder from the stack, or from an approved agent
+        let account = BiddingAgents::bidder();
+        Auction::verify(&account);
+
+        let bidder_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+
+        // Place the bid
+        Swap::bid(account, bid, Some(bidder_purse));
+    }
+
+    pub fn synthetic_bid() {
+        Auction::check_valid();
+
+        // All the details are passed in
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        let bid = runtime::get_named_arg::<U512>(keys::BID);
+        Auction::synth_allowed(&account, &bid);
+
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        Swap::bid(account, bid, Option::None);
+    }
+
+    pub fn cancel_synthetic_bid() {
+        // Only admin is allowed to call this
+        Auction::check_admin();
+
+        // All the details are passed in
+        let account = runtime::get_named_arg::<Key>(keys::BIDDER);
+        Auction::withdraw_synthetic(account);
+    }
+
+    pub fn cancel_auction() {
+        Auction::check_valid();
+
+        // Only owner is allowed to cancel
+        Auction::check_owner();
+
+        Swap::cancel();
+    }
+}
+
+pub mod gift {
+    use alloc::string::String;
+    use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+    use casper_types::{CLValue, ContractPackageHash, Key};
+
+    use crate::accounts::GIFT_ACCOUNT;
+    use crate::error::AuctionError;
+    use crate::gift::Gift;
+    use crate::pause::Pause;
+    use crate::utils::{self, string_to_account_hash};
+    use crate::{keys, migrations};
+
+    fn check_admin() {
+        if string_to_account_hash(GIFT_ACCOUNT) != runtime::get_caller() {
+            runtime::revert(AuctionError::InvalidCaller);
+        }
+    }
+
+    pub fn claim() {
+        // Only admin is allowed to call this
+        check_admin();
+
+        // All the details are passed in
+        let receiver = runtime::get_named_arg::<Key>(keys::RECEIVER);
+        let token_id = runtime::get_named_arg::<String>(keys::TOKEN_ID);
+
+        Gift::claim(receiver, token_id);
+    }
+
+    pub fn cancel() {
+        // Only admin is allowed to call this
+        check_admin();
+
+        // Get the arguments
+        let token_id = runtime::get_named_arg::<String>(keys::TOKEN_ID);
+
+        Gift::cancel(token_id);
+    }
+
+    pub fn gift() {
+        // Only admin is allowed to call this
+        check_admin();
+
+        // Get the arguments
+        let sender = runtime::get_named_arg::<Key>(keys::SENDER);
+        let token_id = runtime::get_named_arg::<String>(keys::TOKEN_ID);
+        let token_package_hash = runtime::get_named_arg::<Key>(keys::TOKEN_PACKAGE_HASH)
+            .into_hash()
+            .unwrap_or_revert_with(AuctionError::MissingTokenPackageHash);
+
+        Gift::gift(sender, token_id, ContractPackageHash::from(token_package_hash));
+    }
+
+    pub fn pause() {
+        // Only admin is allowed to call this
+        check_admin();
+
+        Pause::pause();
+    }
+
+    pub fn unpause() {
+        // Only admin is allowed to call this
+        check_admin();
+
+        // Gifts have no end time to extend
+        Pause::unpause();
+    }
+
+    pub fn migrate() {
+        // Only admin is allowed to call this
+        check_admin();
+        migrations::migrate();
+    }
+
+    pub fn get_gift_count() {
+        runtime::ret(CLValue::from_t(Gift::count()).unwrap_or_revert());
+    }
+
+    pub fn get_gifts() {
+        let offset = runtime::get_named_arg::<u32>(keys::OFFSET);
+        let limit = runtime::get_named_arg::<u32>(keys::LIMIT);
+        runtime::ret(CLValue::from_t(Gift::list(offset, limit)).unwrap_or_revert());
+    }
+
+    // Runs in the context of the new gift contract, so its dictionaries land in its own named keys
+    pub fn init() {
+        utils::add_missing_dict(keys::TOKENS);
+        utils::add_missing_dict(keys::TOKENS_INDEX);
+        utils::add_missing_dict(keys::TOKENS_BY_INDEX);
+        utils::add_missing_dict(keys::EVENTS);
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, Key, U256, U512,
+};
+
+use crate::data::AuctionData;
+use crate::keys::{self, read_named_key_value};
+use crate::kyc::{Kyc, KycMode};
+use crate::pause::Pause;
+use crate::status::{AuctionStatus, ContractType};
+
+/**
+ * Snapshot of an auction returned by the `get_auction_info` view, independent of the named key layout.
+ * Settings a format does not have are `None`.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuctionInfo {
+    pub contract_type: ContractType,
+    pub status: AuctionStatus,
+    pub paused: bool,
+    pub seller: Key,
+    pub token_package_hash: ContractPackageHash,
+    pub token_id: String,
+    pub start_price: Option<U512>,
+    pub reserve_price: Option<U512>,
+    pub swap_price: Option<U512>,
+    // Price a Dutch auction is at while it is live
+    pub current_price: Option<U512>,
+    pub start_time: u64,
+    pub cancel_time: Option<u64>,
+    pub end_time: u64,
+    pub winner: Option<Key>,
+    pub winning_bid: Option<U512>,
+    // Time after which anyone may reject a pending settlement
+    pub settlement_deadline: Option<u64>,
+    pub bid_count: u64,
+    pub bidder_count_cap: Option<u64>,
+    pub auction_timer_extension: Option<u64>,
+    pub minimum_bid_step: Option<U512>,
+    pub minimum_bid_increments: Option<BTreeMap<U512, u32>>,
+    pub kyc_mode: KycMode,
+    pub kyc_level: Option<U256>,
+    pub kyc_recheck: bool,
+    pub marketplace_account: AccountHash,
+    pub marketplace_commission: u32,
+}
+
+// Value of a named key only some formats have
+fn read_optional<T: CLTyped + FromBytes>(name: &str) -> Option<T> {
+    runtime::get_key(name).map(|_| read_named_key_value(name))
+}
+
+impl AuctionInfo {
+    pub fn load() -> AuctionInfo {
+        let contract_type = AuctionData::contract_type();
+        let start_time = AuctionData::start_time();
+        let end_time = AuctionData::end_time();
+        let now = u64::from(runtime::get_blocktime());
+        let current_price = if contract_type == ContractType::DutchAuction && !AuctionData::is_done() && start_time <= now && now < end_time {
+            Some(AuctionData::current_price())
+        } else {
+            None
+        };
+        let (winner, winning_bid) = AuctionData::current_winner();
+        let (marketplace_account, marketplace_commission) = AuctionData::marketplace_data();
+        AuctionInfo {
+            contract_type,
+            status: AuctionData::status(),
+            paused: Pause::is_paused(),
+            seller: AuctionData::token_owner(),
+            token_package_hash: AuctionData::token_package_hash(),
+            token_id: AuctionData::token_id(),
+            start_price: read_optional(keys::START_PRICE),
+            reserve_price: read_optional(keys::RESERVE_PRICE),
+            swap_price: read_optional(keys::SWAP_PRICE),
+            current_price,
+            start_time,
+            cancel_time: read_optional::<Option<u64>>(keys::CANCEL).flatten(),
+            end_time,
+            winner,
+            winning_bid: winning_bid.map(|(bid, _)| bid),
+            settlement_deadline: AuctionData::settlement_deadline(),
+            bid_count: AuctionData::bids().len(),
+            bidder_count_cap: read_optional(keys::BIDDER_NUMBER_CAP),
+            auction_timer_extension: read_optional(keys::AUCTION_TIMER_EXTENSION),
+            minimum_bid_step: read_optional::<Option<U512>>(keys::MINIMUM_BID_STEP).flatten(),
+            minimum_bid_increments: read_optional::<Option<BTreeMap<U512, u32>>>(keys::MINIMUM_BID_INCREMENTS).flatten(),
+            kyc_mode: Kyc::mode(),
+            kyc_level: Kyc::level(),
+            kyc_recheck: Kyc::recheck(),
+            marketplace_account,
+            marketplace_commission,
+        }
+    }
+}
+
+/**
+ * The fields in order, grouped as state, token, prices, times, outcome, bidding rules, gating and
+ * marketplace. Serialized as this tuple, so clients can decode the view without knowing the struct.
+ */
+type AuctionInfoTuple = (
+    (
+        (ContractType, AuctionStatus, bool),
+        (Key, ContractPackageHash, String),
+        ((Option<U512>, Option<U512>), (Option<U512>, Option<U512>)),
+    ),
+    (
+        (u64, Option<u64>, u64),
+        (Option<Key>, Option<U512>, Option<u64>),
+        ((u64, Option<u64>), (Option<u64>, Option<U512>, Option<BTreeMap<U512, u32>>)),
+    ),
+    ((KycMode, Option<U256>, bool), (AccountHash, u32)),
+);
+
+impl CLTyped for AuctionInfo {
+    fn cl_type() -> CLType {
+        AuctionInfoTuple::cl_type()
+    }
+}
+
+impl ToBytes for AuctionInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.contract_type.to_bytes()?);
+        result.extend(self.status.to_bytes()?);
+        result.extend(self.paused.to_bytes()?);
+        result.extend(self.seller.to_bytes()?);
+        result.extend(self.token_package_hash.to_bytes()?);
+        result.extend(self.token_id.to_bytes()?);
+        result.extend(self.start_price.to_bytes()?);
+        result.extend(self.reserve_price.to_bytes()?);
+        result.extend(self.swap_price.to_bytes()?);
+        result.extend(self.current_price.to_bytes()?);
+        result.extend(self.start_time.to_bytes()?);
+        result.extend(self.cancel_time.to_bytes()?);
+        result.extend(self.end_time.to_bytes()?);
+        result.extend(self.winner.to_bytes()?);
+        result.extend(self.winning_bid.to_bytes()?);
+        result.extend(self.settlement_deadline.to_bytes()?);
+        result.extend(self.bid_count.to_bytes()?);
+        result.extend(self.bidder_count_cap.to_bytes()?);
+        result.extend(self.auction_timer_extension.to_bytes()?);
+        result.extend(self.minimum_bid_step.to_bytes()?);
+        result.extend(self.minimum_bid_increments.to_bytes()?);
+        result.extend(self.kyc_mode.to_bytes()?);
+        result.extend(self.kyc_level.to_bytes()?);
+        result.extend(self.kyc_recheck.to_bytes()?);
+        result.extend(self.marketplace_account.to_bytes()?);
+        result.extend(self.marketplace_commission.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_type.serialized_length()
+            + self.status.serialized_length()
+            + self.paused.serialized_length()
+            + self.seller.serialized_length()
+            + self.token_package_hash.serialized_length()
+            + self.token_id.serialized_length()
+            + self.start_price.serialized_length()
+            + self.reserve_price.serialized_length()
+            + self.swap_price.serialized_length()
+            + self.current_price.serialized_length()
+            + self.start_time.serialized_length()
+            + self.cancel_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.winner.serialized_length()
+            + self.winning_bid.serialized_length()
+            + self.settlement_deadline.serialized_length()
+            + self.bid_count.serialized_length()
+            + self.bidder_count_cap.serialized_length()
+            + self.auction_timer_extension.serialized_length()
+            + self.minimum_bid_step.serialized_length()
+            + self.minimum_bid_increments.serialized_length()
+            + self.kyc_mode.serialized_length()
+            + self.kyc_level.serialized_length()
+            + self.kyc_recheck.serialized_length()
+            + self.marketplace_account.serialized_length()
+            + self.marketplace_commission.serialized_length()
+    }
+}
+
+impl FromBytes for AuctionInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_type, remainder) = ContractType::from_bytes(bytes)?;
+        let (status, remainder) = AuctionStatus::from_bytes(remainder)?;
+        let (paused, remainder) = bool::from_bytes(remainder)?;
+        let (seller, remainder) = Key::from_bytes(remainder)?;
+        let (token_package_hash, remainder) = ContractPackageHash::from_bytes(remainder)?;
+        let (token_id, remainder) = String::from_bytes(remainder)?;
+        let (start_price, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (reserve_price, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (swap_price, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (current_price, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (start_time, remainder) = u64::from_bytes(remainder)?;
+        let (cancel_time, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (end_time, remainder) = u64::from_bytes(remainder)?;
+        let (winner, remainder) = Option::<Key>::from_bytes(remainder)?;
+        let (winning_bid, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (settlement_deadline, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (bid_count, remainder) = u64::from_bytes(remainder)?;
+        let (bidder_count_cap, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (auction_timer_extension, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (minimum_bid_step, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (minimum_bid_increments, remainder) = Option::<BTreeMap<U512, u32>>::from_bytes(remainder)?;
+        let (kyc_mode, remainder) = KycMode::from_bytes(remainder)?;
+        let (kyc_level, remainder) = Option::<U256>::from_bytes(remainder)?;
+        let (kyc_recheck, remainder) = bool::from_bytes(remainder)?;
+        let (marketplace_account, remainder) = AccountHash::from_bytes(remainder)?;
+        let (marketplace_commission, remainder) = u32::from_bytes(remainder)?;
+        Ok((
+            AuctionInfo {
+                contract_type,
+                status,
+                paused,
+                seller,
+                token_package_hash,
+                token_id,
+                start_price,
+                reserve_price,
+                swap_price,
+                current_price,
+                start_time,
+                cancel_time,
+                end_time,
+                winner,
+                winning_bid,
+                settlement_deadline,
+                bid_count,
+                bidder_count_cap,
+                auction_timer_extension,
+                minimum_bid_step,
+                minimum_bid_increments,
+                kyc_mode,
+                kyc_level,
+                kyc_recheck,
+                marketplace_account,
+                marketplace_commission,
+            },
+            remainder,
+        ))
+    }
+}
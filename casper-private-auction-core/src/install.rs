@@ -0,0 +1,239 @@
+//! Install routines shared by the installers.
+//! An auction installer only names its format, `auction_installer!` generates its entry points and `call`;
+//! `gift_installer!` does the same for the gift contract.
+
+use alloc::{format, string::String, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, runtime_args, ApiError, ContractHash, ContractPackageHash, EntryPoints,
+    Key, RuntimeArgs,
+};
+
+use crate::constructors::{self, MarketplaceSettings};
+use crate::{entry_points, functions, keys, AuctionError};
+
+/// Export the entry point handlers of a `handlers` module from the calling contract wasm.
+#[macro_export]
+macro_rules! export_handlers {
+    ($module:ident: $($entry_point:ident),* $(,)?) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $entry_point() {
+                $crate::handlers::$module::$entry_point();
+            }
+        )*
+    };
+}
+
+/**
+ * Generate an auction installer: the entry points of its format, the ones every auction shares,
+ * and the `call` installing (or, with `upgrade` set, upgrading) the contract with the format's named keys.
+ */
+#[macro_export]
+macro_rules! auction_installer {
+    ($format:ident, $named_keys:ident, [$($entry_point:ident),* $(,)?]) => {
+        $crate::export_handlers!($format: $($entry_point),*);
+        $crate::export_handlers!(
+            auction: approve,
+            reject,
+            fund_synthetic,
+            refresh_commissions,
+            resume_settlement,
+            redirect_payout,
+            update_gating_list,
+            update_bidding_agents,
+            pause,
+            unpause,
+            get_bid,
+            get_fees,
+            get_auction_info,
+            migrate,
+            init
+        );
+
+        #[no_mangle]
+        pub extern "C" fn call() {
+            $crate::install::install(
+                $crate::entry_points::$format,
+                $crate::constructors::$named_keys,
+            );
+        }
+    };
+}
+
+/// Generate the gift installer: the gift entry points and the `call` installing (or upgrading) the gift contract.
+#[macro_export]
+macro_rules! gift_installer {
+    () => {
+        $crate::export_handlers!(
+            gift: claim,
+            cancel,
+            gift,
+            pause,
+            unpause,
+            migrate,
+            get_gift_count,
+            get_gifts,
+            init
+        );
+
+        #[no_mangle]
+        pub extern "C" fn call() {
+            $crate::install::install_gift();
+        }
+    };
+}
+
+// Marketplace settings the admin keeps in the factory named by the `marketplace_settings` argument
+fn marketplace_settings() -> MarketplaceSettings {
+    let factory = runtime::get_named_arg::<Key>(keys::MARKETPLACE_SETTINGS)
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(AuctionError::KeyNotHash);
+    runtime::call_versioned_contract(factory, None, functions::GET_MARKETPLACE_SETTINGS, runtime_args! {})
+}
+
+// Store the contract hash under the auction designation in the installing account
+fn put_contract_hash(auction_desig: &str, auction_hash: ContractHash) {
+    runtime::put_key(
+        &format!("{}_auction_contract_hash", auction_desig),
+        Key::Hash(auction_hash.value()),
+    );
+    runtime::put_key(
+        &format!("{}_auction_contract_hash_wrapped", auction_desig),
+        storage::new_uref(auction_hash).into(),
+    );
+}
+
+// Add a new version to an auction this account installed before. Its state is migrated by the admin afterwards.
+fn upgrade(auction_desig: &str, auction_package: Key, entry_points: EntryPoints) {
+    let (auction_hash, _) = storage::add_contract_version(
+        ContractPackageHash::new(
+            auction_package
+                .into_hash()
+                .unwrap_or_revert_with(ApiError::User(202)),
+        ),
+        entry_points,
+        NamedKeys::new(),
+    );
+    put_contract_hash(auction_desig, auction_hash);
+}
+
+/**
+ * Install an auction under the `name` argument and transfer the token to it. With the `upgrade`
+ * argument set, a new version is added to the auction this account installed with that name instead.
+ */
+pub fn install(entry_points: fn() -> EntryPoints, named_keys: fn(MarketplaceSettings) -> NamedKeys) {
+    let auction_desig: String = runtime::get_named_arg(keys::NAME);
+    let installed = runtime::get_key(&format!("{}_{}", auction_desig, keys::AUCTION_CONTRACT_HASH));
+    match (installed, runtime::get_named_arg::<bool>(keys::UPGRADE)) {
+        (Some(auction_package), true) => {
+            return upgrade(&auction_desig, auction_package, entry_points())
+        }
+        (Some(_), false) => runtime::revert(AuctionError::AlreadyInstalled),
+        (None, true) => runtime::revert(AuctionError::NotInstalled),
+        (None, false) => {}
+    }
+
+    let (auction_hash, _) = storage::new_contract(
+        entry_points(),
+        Some(named_keys(marketplace_settings())),
+        Some(format!("{}_{}", auction_desig, keys::AUCTION_CONTRACT_HASH)),
+        Some(format!("{}_{}", auction_desig, keys::AUCTION_ACCESS_TOKEN)),
+    );
+    put_contract_hash(&auction_desig, auction_hash);
+
+    // Create the purse and dictionaries in the contract's context
+    runtime::call_contract::<()>(auction_hash, functions::INIT, runtime_args! {});
+
+    // Hash of the NFT contract put up for auction
+    let token_contract_hash = ContractPackageHash::new(
+        runtime::get_named_arg::<Key>(keys::TOKEN_PACKAGE_HASH)
+            .into_hash()
+            .unwrap_or_revert_with(ApiError::User(200)),
+    );
+    // Transfer the NFT ownership to the auction
+    let token_ids = vec![runtime::get_named_arg::<String>(keys::TOKEN_ID)];
+
+    let auction_contract_package_hash =
+        runtime::get_key(&format!("{}_{}", auction_desig, keys::AUCTION_CONTRACT_HASH))
+            .unwrap_or_revert_with(ApiError::User(201));
+    runtime::put_key(
+        &format!("{}_auction_contract_package_hash_wrapped", auction_desig),
+        storage::new_uref(ContractPackageHash::new(
+            auction_contract_package_hash
+                .into_hash()
+                .unwrap_or_revert_with(ApiError::User(202)),
+        ))
+        .into(),
+    );
+    runtime::call_versioned_contract::<()>(
+        token_contract_hash,
+        None,
+        "transfer",
+        runtime_args! {
+            "sender" => Key::Account(runtime::get_caller()),
+            "recipient" => auction_contract_package_hash,
+            "token_ids" => token_ids,
+        },
+    );
+}
+
+// Store the gift contract hash under its name in the installing account
+fn put_gift_contract_hash(contract_name: &str, contract_hash: ContractHash) {
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+}
+
+/**
+ * Install a gift contract under the `contract_name` argument. With the `upgrade` argument set, a new
+ * version is added to the one this account installed with that name instead; its state is migrated
+ * by the admin afterwards.
+ */
+pub fn install_gift() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let package_name = format!("{}_contract_package_hash", contract_name);
+    match (runtime::get_key(&package_name), runtime::get_named_arg::<bool>(keys::UPGRADE)) {
+        (Some(package_key), true) => {
+            let (contract_hash, _) = storage::add_contract_version(
+                ContractPackageHash::new(package_key.into_hash().unwrap_or_revert()),
+                entry_points::gift(),
+                NamedKeys::new(),
+            );
+            return put_gift_contract_hash(&contract_name, contract_hash);
+        }
+        (Some(_), false) => runtime::revert(AuctionError::AlreadyInstalled),
+        (None, true) => runtime::revert(AuctionError::NotInstalled),
+        (None, false) => {}
+    }
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points::gift(),
+        Some(constructors::create_gift_named_keys(contract_name.clone())),
+        Some(package_name.clone()),
+        Some(format!("{}_access_token", contract_name)),
+    );
+    let package_hash = ContractPackageHash::new(
+        runtime::get_key(&package_name)
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    put_gift_contract_hash(&contract_name, contract_hash);
+    runtime::put_key(
+        &format!("{}_package_hash_wrapped", contract_name),
+        storage::new_uref(package_hash).into(),
+    );
+
+    // Create the dictionaries in the contract's context
+    runtime::call_contract::<()>(contract_hash, functions::INIT, runtime_args! {});
+}
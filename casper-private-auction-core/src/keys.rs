@@ -11,6 +11,7 @@ pub const SENDER: &str = "sender";
 pub const RECEIVER: &str = "receiver";
 pub const OWNER: &str = "token_owner";
 pub const BENEFICIARY_ACCOUNT: &str = "beneficiary_account";
+pub const BENEFICIARIES: &str = "beneficiaries";
 pub const AUCTION_PURSE: &str = "auction_purse";
 pub const TOKEN_PACKAGE_HASH: &str = "token_package_hash";
 pub const TOKEN_ID: &str = "token_id";
@@ -32,15 +33,63 @@ pub const EVENTS: &str = "auction_events";
 pub const EVENTS_COUNT: &str = "auction_events_count";
 pub const COMMISSIONS: &str = "commissions";
 pub const KYC_PACKAGE_HASH: &str = "kyc_package_hash";
+pub const KYC_MODE: &str = "kyc_mode";
+pub const KYC_LEVEL: &str = "kyc_level";
+pub const KYC_RECHECK: &str = "kyc_recheck";
+pub const GATING_LIST: &str = "gating_list";
+pub const ACCOUNTS: &str = "accounts";
+pub const LISTED: &str = "listed";
+pub const BIDDING_AGENTS: &str = "bidding_agents";
+pub const AGENT_BIDDERS: &str = "agent_bidders";
+pub const AGENTS: &str = "agents";
+pub const APPROVED: &str = "approved";
+pub const REFUND_PURSE: &str = "refund_purse";
 pub const SYNTHETIC_PACKAGE_HASH: &str = "synth_package_hash";
 pub const BIDDER_NUMBER_CAP: &str = "bidder_count_cap";
 pub const AUCTION_TIMER_EXTENSION: &str = "auction_timer_extension";
 pub const MINIMUM_BID_STEP: &str = "minimum_bid_step";
+pub const MINIMUM_BID_INCREMENTS: &str = "minimum_bid_increments";
+pub const PROXY_BIDS: &str = "proxy_bids";
+pub const MAX_BID: &str = "max_bid";
 pub const MARKETPLACE_COMMISSION: &str = "marketplace_commission";
 pub const MARKETPLACE_ACCOUNT: &str = "marketplace_account";
+pub const MAX_MARKETPLACE_COMMISSION: &str = "max_marketplace_commission";
+pub const MARKETPLACE_SETTINGS: &str = "marketplace_settings";
 pub const NAME: &str = "name";
 pub const TOKENS: &str = "gift_tokens";
 pub const TOKEN_COUNT: &str = "token_count";
+pub const TOKENS_INDEX: &str = "gift_tokens_index";
+pub const TOKENS_BY_INDEX: &str = "gift_tokens_by_index";
+pub const OFFSET: &str = "offset";
+pub const LIMIT: &str = "limit";
+pub const SETTLEMENT_PAYOUTS: &str = "settlement_payouts";
+pub const RECIPIENT_PURSES: &str = "recipient_purses";
+pub const SETTLEMENT_PROGRESS: &str = "settlement_progress";
+pub const SETTLEMENT_STATUS: &str = "settlement_status";
+pub const SETTLEMENT_TIMEOUT: &str = "settlement_timeout";
+pub const SETTLEMENT_DEADLINE: &str = "settlement_deadline";
+pub const OFFER_NEXT: &str = "offer_next";
+pub const HOLDING_ACCOUNT: &str = "holding_account";
+pub const PAUSED_AT: &str = "paused_at";
+pub const EXTEND_END_TIME: &str = "extend_end_time";
+pub const REASON: &str = "reason";
+pub const SELLER_CANCELLATION_PENALTY: &str = "seller_cancellation_penalty";
+pub const PENALTY_PURSE: &str = "penalty_purse";
+pub const STATE_VERSION: &str = "state_version";
+pub const UPGRADE: &str = "upgrade";
+pub const FACTORY: &str = "factory_package_hash";
+pub const FACTORY_PACKAGE_HASH: &str = "auction_factory_package_hash";
+pub const FACTORY_ACCESS_TOKEN: &str = "auction_factory_access_token";
+pub const FACTORY_RECORDS: &str = "factory_records";
+pub const FACTORY_LISTS: &str = "factory_lists";
+pub const FACTORY_INDEX: &str = "factory_index";
+pub const SELLER: &str = "seller";
+pub const AUCTION: &str = "auction";
+pub const FACTORY_CREDIT_LINES: &str = "factory_credit_lines";
+pub const FACTORY_CREDIT_HOLDS: &str = "factory_credit_holds";
+pub const ACCOUNT: &str = "account";
+pub const AMOUNT: &str = "amount";
+pub const CREDIT_LIMIT: &str = "credit_limit";
 
 // TODO: This needs A LOT of error handling because we don't want an auction being left in an unrecoverable state if the named keys are bad!
 pub fn read_named_key_uref(name: &str) -> URef {
@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    runtime_args, CLType, CLTyped, Key, RuntimeArgs, U256,
+};
+
+use crate::data::AuctionData;
+use crate::error::AuctionError;
+use crate::keys::{self, read_named_key_value};
+use crate::Dict;
+
+/**
+ * Who may bid on an auction. The external mode asks the KYC contract, optionally for a tier given
+ * by the `kyc_level`; the list modes read the gating list the seller and admin maintain.
+ */
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KycMode {
+    Open = 0,
+    External = 1,
+    Allowlist = 2,
+    Denylist = 3,
+}
+
+impl KycMode {
+    pub fn from_u8(value: u8) -> Option<KycMode> {
+        match value {
+            0 => Some(KycMode::Open),
+            1 => Some(KycMode::External),
+            2 => Some(KycMode::Allowlist),
+            3 => Some(KycMode::Denylist),
+            _ => None,
+        }
+    }
+}
+
+impl CLTyped for KycMode {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for KycMode {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for KycMode {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (value, remainder) = u8::from_bytes(bytes)?;
+        let mode = KycMode::from_u8(value).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((mode, remainder))
+    }
+}
+
+pub struct Kyc;
+
+impl Kyc {
+    pub fn mode() -> KycMode {
+        read_named_key_value(keys::KYC_MODE)
+    }
+
+    // Tier asked from the KYC contract, `None` for its base check
+    pub fn level() -> Option<U256> {
+        read_named_key_value(keys::KYC_LEVEL)
+    }
+
+    // Whether the winner is checked again at settlement
+    pub fn recheck() -> bool {
+        read_named_key_value(keys::KYC_RECHECK)
+    }
+
+    pub fn is_verified(account: &Key) -> bool {
+        match Self::mode() {
+            KycMode::Open => true,
+            KycMode::External => {
+                let contract_package_hash = AuctionData::kyc_package_hash()
+                    .unwrap_or_revert_with(AuctionError::KYCError);
+                runtime::call_versioned_contract::<bool>(
+                    contract_package_hash,
+                    None,
+                    "is_kyc_proved",
+                    runtime_args! {
+                        "account" => *account,
+                        "index" => Self::level(),
+                    },
+                )
+            }
+            KycMode::Allowlist => Self::is_listed(account),
+            KycMode::Denylist => !Self::is_listed(account),
+        }
+    }
+
+    pub fn is_listed(account: &Key) -> bool {
+        Dict::at(keys::GATING_LIST)
+            ._get::<bool>(&account.to_formatted_string())
+            .unwrap_or_default()
+    }
+
+    // Add accounts to, or take them off, the gating list
+    pub fn update_list(accounts: &[Key], listed: bool) {
+        let list = Dict::at(keys::GATING_LIST);
+        for account in accounts {
+            if listed {
+                list.set(&account.to_formatted_string(), true);
+            } else {
+                list._remove::<bool>(&account.to_formatted_string());
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@ use casper_types::{
 };
 
 use error::AuctionError;
+use utils::dict_key;
 
 
 pub mod auction;
@@ -19,6 +20,7 @@ pub mod error;
 #[macro_use]
 pub mod data;
 pub mod bids;
+pub mod commissions;
 pub mod events;
 pub mod keys;
 pub mod functions;
@@ -29,6 +31,19 @@ pub mod english;
 pub mod dutch;
 pub mod swap;
 pub mod gift;
+pub mod gifts;
+pub mod pause;
+pub mod kyc;
+pub mod status;
+pub mod migrations;
+pub mod entry_points;
+pub mod handlers;
+pub mod install;
+pub mod info;
+pub mod registry;
+pub mod credit;
+pub mod agents;
+pub mod proxy;
 
 struct Dict {
     uref: URef,
@@ -45,16 +60,16 @@ impl Dict {
     }
 
     pub fn _get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T> {
-        storage::dictionary_get(self.uref, key)
+        storage::dictionary_get(self.uref, &dict_key(key))
             .unwrap_or_revert_with(AuctionError::DictionaryGetFail)
             .unwrap_or_default()
     }
 
     pub fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T) {
-        storage::dictionary_put(self.uref, key, Some(value));
+        storage::dictionary_put(self.uref, &dict_key(key), Some(value));
     }
 
     pub fn _remove<T: CLTyped + ToBytes>(&self, key: &str) {
-        storage::dictionary_put(self.uref, key, Option::<T>::None);
+        storage::dictionary_put(self.uref, &dict_key(key), Option::<T>::None);
     }
 }
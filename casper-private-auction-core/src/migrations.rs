@@ -0,0 +1,117 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, CLTyped, ContractPackageHash, Key, U256, U512};
+
+use crate::commissions;
+use crate::data::AuctionData;
+use crate::error::AuctionError;
+use crate::kyc::KycMode;
+use crate::status::ContractType;
+use crate::keys::{self, read_named_key_value, write_named_key_value};
+use crate::utils;
+
+/**
+ * Version of the named key layout written by this crate. Bump it together with a new
+ * migration step whenever a released contract would need its named keys rewritten.
+ */
+pub const STATE_VERSION: u32 = 1;
+
+// Contracts installed before state versioning have no version key
+pub fn state_version() -> u32 {
+    match runtime::get_key(keys::STATE_VERSION) {
+        Some(_) => read_named_key_value(keys::STATE_VERSION),
+        None => 0,
+    }
+}
+
+/**
+ * Bring the named keys of the calling contract up to the current layout, one version at a time.
+ * Running it on an up to date contract does nothing.
+ */
+pub fn migrate() {
+    let mut version = state_version();
+    if version > STATE_VERSION {
+        runtime::revert(AuctionError::StateVersionTooNew)
+    }
+    while version < STATE_VERSION {
+        match version {
+            0 => migrate_to_v1(),
+            _ => runtime::revert(AuctionError::StateVersionTooNew),
+        }
+        version += 1;
+    }
+    add_missing_key(keys::STATE_VERSION, version);
+    write_named_key_value(keys::STATE_VERSION, version);
+}
+
+/**
+ * Version 1: every key added since the first release, with the marketplace commission in basis
+ * points and bidders keyed by `Key`. Existing auctions keep their behaviour:
+ * the commissions and beneficiary they settle with are frozen now, gating follows the KYC
+ * contract if there is one, and timeouts, agents, proxy bids and relative increments are off.
+ */
+fn migrate_to_v1() {
+    add_missing_key(keys::PAUSED_AT, Option::<u64>::None);
+    add_missing_key(keys::FACTORY, Option::<ContractPackageHash>::None);
+    let contract_type = AuctionData::contract_type();
+    if contract_type == ContractType::Gift {
+        // Gifts escrowed before are not indexed, they can still be claimed or cancelled
+        utils::add_missing_dict(keys::TOKENS_INDEX);
+        utils::add_missing_dict(keys::TOKENS_BY_INDEX);
+        return;
+    }
+
+    // Bidders are keyed explicitly, version 0 kept the bids and the winner by account hash
+    AuctionData::bids().rekey_accounts();
+    let winner: Option<AccountHash> = read_named_key_value(keys::CURRENT_WINNER);
+    write_named_key_value(keys::CURRENT_WINNER, winner.map(Key::Account));
+    // Version 0 kept the marketplace commission in per-mille, it is in basis points since
+    let marketplace_commission: u32 = read_named_key_value(keys::MARKETPLACE_COMMISSION);
+    write_named_key_value(keys::MARKETPLACE_COMMISSION, marketplace_commission * 10);
+
+    if runtime::get_key(keys::COMMISSIONS).is_none() {
+        let commissions = commissions::to_keyed_commissions(AuctionData::fetch_commissions());
+        add_missing_key(keys::COMMISSIONS, commissions);
+    }
+    if runtime::get_key(keys::BENEFICIARIES).is_none() {
+        let mut beneficiaries = BTreeMap::new();
+        beneficiaries.insert(Key::Account(AuctionData::beneficiary_account()), utils::BASIS_POINTS);
+        add_missing_key(keys::BENEFICIARIES, beneficiaries);
+    }
+    if runtime::get_key(keys::RECIPIENT_PURSES).is_none() {
+        let commissions: BTreeMap<Key, u32> = read_named_key_value(keys::COMMISSIONS);
+        let beneficiaries: BTreeMap<Key, u32> = read_named_key_value(keys::BENEFICIARIES);
+        add_missing_key(
+            keys::RECIPIENT_PURSES,
+            commissions::resolve_purses(commissions.keys().chain(beneficiaries.keys())),
+        );
+    }
+    add_missing_key(keys::SETTLEMENT_PAYOUTS, Vec::<(Key, U512)>::new());
+    add_missing_key(keys::SETTLEMENT_PROGRESS, 0_u32);
+    add_missing_key(keys::SETTLEMENT_STATUS, AuctionData::status());
+    add_missing_key(keys::SETTLEMENT_TIMEOUT, Option::<u64>::None);
+    add_missing_key(keys::SETTLEMENT_DEADLINE, Option::<u64>::None);
+
+    let kyc_mode = match AuctionData::kyc_package_hash() {
+        Some(_) => KycMode::External,
+        None => KycMode::Open,
+    };
+    add_missing_key(keys::KYC_MODE, kyc_mode);
+    add_missing_key(keys::KYC_LEVEL, Option::<U256>::None);
+    add_missing_key(keys::KYC_RECHECK, false);
+    utils::add_missing_dict(keys::GATING_LIST);
+    utils::add_missing_dict(keys::BIDDING_AGENTS);
+    utils::add_missing_dict(keys::AGENT_BIDDERS);
+
+    if contract_type == ContractType::EnglishAuction {
+        add_missing_key(keys::SELLER_CANCELLATION_PENALTY, Option::<u32>::None);
+        add_missing_key(keys::MINIMUM_BID_INCREMENTS, Option::<BTreeMap<U512, u32>>::None);
+        utils::add_missing_dict(keys::PROXY_BIDS);
+    }
+}
+
+fn add_missing_key<T: CLTyped + ToBytes>(name: &str, value: T) {
+    if runtime::get_key(name).is_none() {
+        runtime::put_key(name, storage::new_uref(value).into());
+    }
+}
@@ -0,0 +1,46 @@
+use casper_contract::contract_api::runtime;
+
+use crate::error::AuctionError;
+use crate::events::{emit, AuctionEvent};
+use crate::keys::{self, read_named_key_value, write_named_key_value};
+
+/**
+ * Emergency circuit breaker shared by the auctions and the gift contract.
+ * The time the contract was paused at is kept in a named key, `None` while running.
+ */
+pub struct Pause;
+
+impl Pause {
+    pub fn paused_at() -> Option<u64> {
+        read_named_key_value(keys::PAUSED_AT)
+    }
+
+    pub fn is_paused() -> bool {
+        Self::paused_at().is_some()
+    }
+
+    // Revert if the contract is paused
+    pub fn check() {
+        if Self::is_paused() {
+            runtime::revert(AuctionError::ContractPaused)
+        }
+    }
+
+    pub fn pause() {
+        Self::check();
+        write_named_key_value(keys::PAUSED_AT, Some(u64::from(runtime::get_blocktime())));
+        emit(&AuctionEvent::Paused {})
+    }
+
+    // Lift the pause, returns how long the contract was paused
+    pub fn unpause() -> u64 {
+        let paused_at = match Self::paused_at() {
+            Some(time) => time,
+            None => runtime::revert(AuctionError::ContractNotPaused),
+        };
+        let duration = u64::from(runtime::get_blocktime()).saturating_sub(paused_at);
+        write_named_key_value(keys::PAUSED_AT, Option::<u64>::None);
+        emit(&AuctionEvent::Unpaused { duration });
+        duration
+    }
+}
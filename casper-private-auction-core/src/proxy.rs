@@ -0,0 +1,41 @@
+use casper_contract::contract_api::runtime;
+use casper_types::{Key, U512};
+
+use crate::{keys, Dict};
+
+/**
+ * Maximum bids of English proxy bidders. A proxy bidder escrows its maximum in the auction purse and the
+ * auction raises its visible bid for it when it is outbid, one increment above the competing bid.
+ * Only the leading bidder holds a maximum above its visible bid, an outbid proxy is raised to its maximum.
+ */
+pub struct ProxyBids;
+
+impl ProxyBids {
+    // Formats without proxy bidding have no maximums
+    pub fn max(bidder: &Key) -> Option<U512> {
+        runtime::get_key(keys::PROXY_BIDS)?;
+        Dict::at(keys::PROXY_BIDS)._get(&bidder.to_formatted_string())
+    }
+
+    pub fn set(bidder: &Key, max: U512) {
+        Dict::at(keys::PROXY_BIDS).set(&bidder.to_formatted_string(), max);
+    }
+
+    pub fn clear(bidder: &Key) {
+        if Self::max(bidder).is_some() {
+            Dict::at(keys::PROXY_BIDS)._remove::<U512>(&bidder.to_formatted_string());
+        }
+    }
+
+    // Motes the auction holds for a funded bid
+    pub fn escrow(bidder: &Key, bid: U512) -> U512 {
+        Self::max(bidder).unwrap_or(bid)
+    }
+
+    // Motes the auction holds for a funded bid that leaves the auction, dropping its maximum
+    pub fn take_escrow(bidder: &Key, bid: U512) -> U512 {
+        let escrow = Self::escrow(bidder, bid);
+        Self::clear(bidder);
+        escrow
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::{format, string::String, vec::Vec};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::NamedKeys,
+    CLType, CLTyped, ContractPackageHash, Key,
+};
+
+use crate::error::AuctionError;
+use crate::status::{AuctionStatus, ContractType};
+use crate::{keys, utils, Dict};
+
+const ALL: &str = "all";
+
+/// Registry entry of a contract created by the factory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuctionRecord {
+    pub auction: ContractPackageHash,
+    pub contract_type: ContractType,
+    // Last status pulled from the contract
+    pub status: AuctionStatus,
+    pub seller: Key,
+    // Token package and id put up, gift contracts hold no single token
+    pub token: Option<(ContractPackageHash, String)>,
+}
+
+// Serialized as this tuple, so clients can decode records without knowing the struct
+type AuctionRecordTuple = (
+    ContractPackageHash,
+    (ContractType, AuctionStatus),
+    (Key, Option<(ContractPackageHash, String)>),
+);
+
+impl CLTyped for AuctionRecord {
+    fn cl_type() -> CLType {
+        AuctionRecordTuple::cl_type()
+    }
+}
+
+impl ToBytes for AuctionRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.auction.to_bytes()?);
+        result.extend(self.contract_type.to_bytes()?);
+        result.extend(self.status.to_bytes()?);
+        result.extend(self.seller.to_bytes()?);
+        result.extend(self.token.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.auction.serialized_length()
+            + self.contract_type.serialized_length()
+            + self.status.serialized_length()
+            + self.seller.serialized_length()
+            + self.token.serialized_length()
+    }
+}
+
+impl FromBytes for AuctionRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (auction, remainder) = ContractPackageHash::from_bytes(bytes)?;
+        let (contract_type, remainder) = ContractType::from_bytes(remainder)?;
+        let (status, remainder) = AuctionStatus::from_bytes(remainder)?;
+        let (seller, remainder) = Key::from_bytes(remainder)?;
+        let (token, remainder) = Option::<(ContractPackageHash, String)>::from_bytes(remainder)?;
+        Ok((
+            AuctionRecord {
+                auction,
+                contract_type,
+                status,
+                seller,
+                token,
+            },
+            remainder,
+        ))
+    }
+}
+
+/**
+ * Registry of the contracts created by the factory. Records are stored by creation index,
+ * and indexed in lists of all contracts, per seller, per status and per token id.
+ */
+pub struct Registry {
+    records: Dict,
+    lists: Dict,
+    index: Dict,
+}
+
+impl Registry {
+    // Creates the registry dictionaries, to be used only once when installing the factory.
+    pub fn init(named_keys: &mut NamedKeys) {
+        utils::add_empty_dict(named_keys, keys::FACTORY_RECORDS);
+        utils::add_empty_dict(named_keys, keys::FACTORY_LISTS);
+        utils::add_empty_dict(named_keys, keys::FACTORY_INDEX);
+    }
+
+    pub fn at() -> Registry {
+        Registry {
+            records: Dict::at(keys::FACTORY_RECORDS),
+            lists: Dict::at(keys::FACTORY_LISTS),
+            index: Dict::at(keys::FACTORY_INDEX),
+        }
+    }
+
+    pub fn len(&self) -> u32 {
+        self.list_len(ALL)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Adds a new contract, returns its index
+    pub fn register(&self, record: AuctionRecord) -> u32 {
+        let index = self.len();
+        self.index.set(&record.auction.to_formatted_string(), index);
+        self.push(ALL, index);
+        self.push(&seller_list(&record.seller), index);
+        self.push(&status_list(record.status), index);
+        if let Some((_, token_id)) = &record.token {
+            self.push(&token_list(token_id), index);
+        }
+        self.records.set(&index.to_string(), record);
+        index
+    }
+
+    // Moves a registered contract to the list of the status pulled from it
+    pub fn update_status(&self, auction: ContractPackageHash, status: AuctionStatus) {
+        let index: u32 = self
+            .index
+            ._get(&auction.to_formatted_string())
+            .unwrap_or_revert_with(AuctionError::AuctionNotRegistered);
+        let mut record = self
+            .record(index)
+            .unwrap_or_revert_with(AuctionError::AuctionNotRegistered);
+        if record.status == status {
+            return;
+        }
+        self.remove(&status_list(record.status), index);
+        self.push(&status_list(status), index);
+        record.status = status;
+        self.records.set(&index.to_string(), record);
+    }
+
+    pub fn is_registered(&self, auction: ContractPackageHash) -> bool {
+        self.index._get::<u32>(&auction.to_formatted_string()).is_some()
+    }
+
+    pub fn record_of(&self, auction: ContractPackageHash) -> Option<AuctionRecord> {
+        self.record(self.index._get(&auction.to_formatted_string())?)
+    }
+
+    pub fn record(&self, index: u32) -> Option<AuctionRecord> {
+        self.records._get(&index.to_string())
+    }
+
+    pub fn all(&self, offset: u32, limit: u32) -> Vec<AuctionRecord> {
+        self.page(ALL, offset, limit)
+    }
+
+    pub fn by_seller(&self, seller: &Key, offset: u32, limit: u32) -> Vec<AuctionRecord> {
+        self.page(&seller_list(seller), offset, limit)
+    }
+
+    pub fn by_status(&self, status: AuctionStatus, offset: u32, limit: u32) -> Vec<AuctionRecord> {
+        self.page(&status_list(status), offset, limit)
+    }
+
+    // Token ids are only unique within a token package, so the list is filtered by package before paging
+    pub fn by_token(
+        &self,
+        token_package_hash: ContractPackageHash,
+        token_id: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AuctionRecord> {
+        let list = token_list(token_id);
+        (0..self.list_len(&list))
+            .filter_map(|position| self.list_at(&list, position))
+            .filter_map(|index| self.record(index))
+            .filter(|record| {
+                record
+                    .token
+                    .as_ref()
+                    .map_or(false, |(package_hash, _)| *package_hash == token_package_hash)
+            })
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    fn page(&self, list: &str, offset: u32, limit: u32) -> Vec<AuctionRecord> {
+        let end = offset.saturating_add(limit).min(self.list_len(list));
+        (offset..end)
+            .filter_map(|position| self.list_at(list, position))
+            .filter_map(|index| self.record(index))
+            .collect()
+    }
+
+    fn list_len(&self, list: &str) -> u32 {
+        self.lists._get(&format!("{}_len", list)).unwrap_or_default()
+    }
+
+    fn list_at(&self, list: &str, position: u32) -> Option<u32> {
+        self.lists._get(&format!("{}_{}", list, position))
+    }
+
+    fn push(&self, list: &str, index: u32) {
+        let len = self.list_len(list);
+        self.lists.set(&format!("{}_{}", list, len), index);
+        self.lists.set(&format!("{}_pos_{}", list, index), len);
+        self.lists.set(&format!("{}_len", list), len + 1);
+    }
+
+    // Swap-remove, the last entry of the list takes the place of the removed one
+    fn remove(&self, list: &str, index: u32) {
+        let position: u32 = match self.lists._get(&format!("{}_pos_{}", list, index)) {
+            Some(position) => position,
+            None => return,
+        };
+        let last = self.list_len(list) - 1;
+        if position < last {
+            let moved = self
+                .list_at(list, last)
+                .unwrap_or_revert_with(AuctionError::DictionaryGetFail);
+            self.lists.set(&format!("{}_{}", list, position), moved);
+            self.lists.set(&format!("{}_pos_{}", list, moved), position);
+        }
+        self.lists._remove::<u32>(&format!("{}_{}", list, last));
+        self.lists._remove::<u32>(&format!("{}_pos_{}", list, index));
+        self.lists.set(&format!("{}_len", list), last);
+    }
+}
+
+fn seller_list(seller: &Key) -> String {
+    format!("seller_{}", seller.to_formatted_string())
+}
+
+fn status_list(status: AuctionStatus) -> String {
+    format!("status_{}", status as u8)
+}
+
+fn token_list(token_id: &str) -> String {
+    format!("token_{}", token_id)
+}
@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+
+/**
+ * Lifecycle of an auction. Stored as its `u8` discriminant, so named keys written before the
+ * enum existed still read back.
+ */
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AuctionStatus {
+    Live = 0,
+    Cancelled = 1,
+    PendingSettle = 2,
+    Rejected = 3,
+    Settled = 4,
+    // Payouts are in progress towards the stored settlement status
+    Settling = 5,
+    Voided = 6,
+}
+
+impl AuctionStatus {
+    pub fn from_u8(value: u8) -> Option<AuctionStatus> {
+        match value {
+            0 => Some(AuctionStatus::Live),
+            1 => Some(AuctionStatus::Cancelled),
+            2 => Some(AuctionStatus::PendingSettle),
+            3 => Some(AuctionStatus::Rejected),
+            4 => Some(AuctionStatus::Settled),
+            5 => Some(AuctionStatus::Settling),
+            6 => Some(AuctionStatus::Voided),
+            _ => None,
+        }
+    }
+
+    // Nothing can be bid or cancelled once the auction left the live status
+    pub fn is_done(self) -> bool {
+        self != AuctionStatus::Live
+    }
+
+    /**
+     * The state transition table. Settling is not listed: an auction enters it on its way to one of
+     * these targets, and leaves it only for the target it was entered for.
+     * A Dutch auction or swap whose synthetic bid is withdrawn goes back from pending settlement to live.
+     */
+    pub fn can_transition_to(self, next: AuctionStatus) -> bool {
+        use AuctionStatus::*;
+        matches!(
+            (self, next),
+            (Live, PendingSettle)
+                | (Live, Settled)
+                | (Live, Cancelled)
+                | (Live, Voided)
+                | (PendingSettle, Settled)
+                | (PendingSettle, Rejected)
+                | (PendingSettle, Live)
+        )
+    }
+}
+
+impl CLTyped for AuctionStatus {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for AuctionStatus {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for AuctionStatus {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (value, remainder) = u8::from_bytes(bytes)?;
+        let status = AuctionStatus::from_u8(value).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((status, remainder))
+    }
+}
+
+/// Format of a contract, stored as its `u8` discriminant like the status.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContractType {
+    EnglishAuction = 2,
+    DutchAuction = 3,
+    Swap = 4,
+    Gift = 5,
+}
+
+impl ContractType {
+    pub fn from_u8(value: u8) -> Option<ContractType> {
+        match value {
+            2 => Some(ContractType::EnglishAuction),
+            3 => Some(ContractType::DutchAuction),
+            4 => Some(ContractType::Swap),
+            5 => Some(ContractType::Gift),
+            _ => None,
+        }
+    }
+}
+
+impl CLTyped for ContractType {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for ContractType {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for ContractType {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (value, remainder) = u8::from_bytes(bytes)?;
+        let contract_type = ContractType::from_u8(value).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((contract_type, remainder))
+    }
+}
@@ -1,17 +1,15 @@
 use casper_contract::contract_api::runtime;
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 pub use casper_types::{
     ApiError, bytesrepr::FromBytes, CLTyped, ContractHash, contracts::NamedKeys,
     Key, runtime_args, RuntimeArgs, system::CallStackElement, U512, URef,
 };
 pub use casper_types::bytesrepr::ToBytes;
 
-use crate::{
-    data::AuctionData,
-    events::{AuctionEvent, emit},
-};
+use crate::data::AuctionData;
+use crate::agents::BiddingAgents;
 use crate::auction::Auction;
-use crate::data::{AUCTION_CANCELLED, AUCTION_PENDING_SETTLE, AUCTION_SETTLED};
+use crate::credit::Credit;
+use crate::status::AuctionStatus;
 use crate::error::AuctionError;
 
 pub struct Swap;
@@ -20,14 +18,13 @@ impl Swap {
     /**
      * Hit the swap
      */
-    pub fn bid(account: Key, bid: U512, bidder_purse: Option<URef>) {
+    pub fn bid(bidder: Key, bid: U512, bidder_purse: Option<URef>) {
         // Get computed current price
         let swap_price = AuctionData::swap_price();
         if swap_price != bid {
             runtime::revert(AuctionError::InvalidPrices);
         }
-        let bidder = account.into_account()
-            .unwrap_or_revert_with(AuctionError::KeyNotAccount);
+        BiddingAgents::check_bidder(&bidder);
 
         // Save the price
         let mut bids = AuctionData::bids();
@@ -41,13 +38,11 @@ impl Swap {
             Auction::move_bid_funds(bidder_purse, swap_price.clone());
             // Settle the auction
             // TODO: can this be optimized to settle from this purse directly?
-            Auction::settle(Some(bidder.into()));
-            AuctionData::update_status(AUCTION_SETTLED);
-            emit(&AuctionEvent::Settled { account: Some(bidder), bid: Some((swap_price, false)) })
+            Auction::settle(Some(bidder), AuctionStatus::Settled);
         } else {
             // Cannot settle auction, however put it into pending settle
-            AuctionData::update_status(AUCTION_PENDING_SETTLE);
-            emit(&AuctionEvent::PendingSettlement { account: bidder, bid: (swap_price, true) })
+            Credit::reserve(bidder, bid);
+            Auction::await_settlement(bidder, (swap_price, true))
         }
     }
 
@@ -55,9 +50,7 @@ impl Swap {
      * Cancel the auction
      */
     pub fn cancel() {
-        Auction::settle(None);
-        AuctionData::update_status(AUCTION_CANCELLED);
-        emit(&AuctionEvent::Cancelled { })
+        Auction::settle(None, AuctionStatus::Cancelled);
     }
 }
 
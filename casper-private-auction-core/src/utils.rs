@@ -1,10 +1,11 @@
 use alloc::format;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use casper_contract::contract_api::runtime;
 use casper_contract::contract_api::runtime::revert;
 use casper_contract::contract_api::storage::new_dictionary;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::account::AccountHash;
+use casper_types::U512;
 use casper_types::contracts::NamedKeys;
 use crate::AuctionError;
 
@@ -16,6 +17,23 @@ pub fn add_empty_dict(named_keys: &mut NamedKeys, name: &str) {
     named_keys.insert(name.to_string(), dict.into());
 }
 
+// Create a dictionary in the named keys of the calling contract, unless it already has one by that name
+pub fn add_missing_dict(name: &str) {
+    if runtime::get_key(name).is_none() {
+        new_dictionary(name).unwrap_or_revert_with(AuctionError::CannotCreateDictionary);
+    }
+}
+
+// Dictionary item keys are limited to 64 bytes, longer keys are stored under their hash
+const DICTIONARY_ITEM_KEY_MAX_LENGTH: usize = 64;
+
+pub fn dict_key(key: &str) -> String {
+    if key.len() <= DICTIONARY_ITEM_KEY_MAX_LENGTH {
+        return key.to_string();
+    }
+    runtime::blake2b(key).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 pub fn string_to_account_hash(account_string: &str) -> AccountHash {
     let account = if account_string.starts_with("account-hash-") {
         AccountHash::from_formatted_str(account_string)
@@ -35,9 +53,17 @@ pub fn string_to_account_hash(account_string: &str) -> AccountHash {
     }
 }
 
+// All commission math is done in basis points (1/10000 of the amount)
+pub const BASIS_POINTS: u32 = 10_000;
+
 pub fn string_to_u16(ustr: &str) -> u16 {
     match ustr.parse::<u16>() {
         Ok(u) => u,
         Err(_e) => revert(AuctionError::CommissionRateIncorrectSerialization),
     }
+}
+
+// Share of an amount for a rate in basis points, always rounded down to the mote
+pub fn share_of(amount: U512, rate: u32) -> U512 {
+    (amount * rate) / BASIS_POINTS
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec};
+
+use casper_contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_private_auction_core::{functions, keys};
+use casper_types::{
+    contracts::NamedKeys, runtime_args, CLType, ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, URef, U512,
+};
+
+const FUNDS: &str = "funds";
+const REFUNDS: &str = "refunds";
+const AMOUNT: &str = "amount";
+
+fn purse(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+// Bid on an auction for a contract bidder from the funds of the agent, its refunds go to the refunds purse
+#[no_mangle]
+pub extern "C" fn bid() {
+    let auction = runtime::get_named_arg::<Key>(keys::AUCTION)
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+    let bidder: Key = runtime::get_named_arg(keys::BIDDER);
+    let bid: U512 = runtime::get_named_arg(keys::BID);
+    runtime::call_versioned_contract::<()>(auction, None, functions::BID, runtime_args! {
+        keys::BID => bid,
+        keys::BID_PURSE => purse(FUNDS),
+        keys::BIDDER => bidder,
+        keys::REFUND_PURSE => purse(REFUNDS).into_add(),
+    });
+}
+
+/**
+ * Contract bidding on behalf of other contracts, like a DAO or custody contract would. The installer
+ * moves `amount` from its main purse into the funds of the agent.
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let amount: U512 = runtime::get_named_arg(AMOUNT);
+    let funds = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), funds, amount, None).unwrap_or_revert();
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(FUNDS), funds.into());
+    named_keys.insert(String::from(REFUNDS), system::create_purse().into());
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::BID,
+        vec![
+            Parameter::new(keys::AUCTION, CLType::Key),
+            Parameter::new(keys::BIDDER, CLType::Key),
+            Parameter::new(keys::BID, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(format!("{}_package_hash", contract_name)),
+        None,
+    );
+    let package_hash = runtime::get_key(&format!("{}_package_hash", contract_name))
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+    runtime::put_key(&format!("{}_contract_hash_wrapped", contract_name), storage::new_uref(contract_hash).into());
+    runtime::put_key(&format!("{}_package_hash_wrapped", contract_name), storage::new_uref(package_hash).into());
+}
@@ -0,0 +1,136 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_private_auction_core::bids::Bids;
+use casper_private_auction_core::constructors::MarketplaceSettings;
+use casper_private_auction_core::keys::{self, read_named_key_uref, write_named_key_value};
+use casper_private_auction_core::{accounts, functions, install};
+use casper_types::{
+    account::AccountHash, contracts::NamedKeys, CLType, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, URef, U512,
+};
+
+macro_rules! named_keys {
+    ( $( ($name:expr, $value:expr) ),* ) => {
+        {
+            let mut named_keys = NamedKeys::new();
+            $( named_keys.insert($name.into(), storage::new_uref($value).into()); )*
+            named_keys
+        }
+    };
+}
+
+fn package_hash_arg(name: &str) -> Option<ContractPackageHash> {
+    runtime::get_named_arg::<Key>(name).into_hash().map(ContractPackageHash::new)
+}
+
+/**
+ * Named keys of an English auction of the first release, with the types it stored them with:
+ * `u8` format and status, a per-mille marketplace commission and the winner as an account hash.
+ */
+fn legacy_english_auction_named_keys(marketplace_settings: MarketplaceSettings) -> NamedKeys {
+    let (marketplace_account, _) = marketplace_settings;
+    named_keys!(
+        (keys::CONTRACT_TYPE, 2_u8),
+        (keys::OWNER, Key::Account(runtime::get_caller())),
+        (keys::BENEFICIARY_ACCOUNT, runtime::get_named_arg::<Key>(keys::BENEFICIARY_ACCOUNT)),
+        (keys::TOKEN_PACKAGE_HASH, package_hash_arg(keys::TOKEN_PACKAGE_HASH).unwrap_or_revert()),
+        (keys::KYC_PACKAGE_HASH, package_hash_arg(keys::KYC_PACKAGE_HASH)),
+        (keys::SYNTHETIC_PACKAGE_HASH, package_hash_arg(keys::SYNTHETIC_PACKAGE_HASH)),
+        (keys::TOKEN_ID, runtime::get_named_arg::<String>(keys::TOKEN_ID)),
+        (keys::START, runtime::get_named_arg::<u64>(keys::START)),
+        (keys::CANCEL, runtime::get_named_arg::<Option<u64>>(keys::CANCEL)),
+        (keys::END, runtime::get_named_arg::<u64>(keys::END)),
+        (keys::RESERVE_PRICE, runtime::get_named_arg::<U512>(keys::RESERVE_PRICE)),
+        (keys::WINNING_BID, Option::<(U512, bool)>::None),
+        (keys::CURRENT_WINNER, Option::<AccountHash>::None),
+        (keys::STATUS, 0_u8),
+        (keys::EVENTS_COUNT, 0_u32),
+        (
+            keys::BIDDER_NUMBER_CAP,
+            runtime::get_named_arg::<Option<u64>>(keys::BIDDER_NUMBER_CAP).unwrap_or(5)
+        ),
+        (
+            keys::AUCTION_TIMER_EXTENSION,
+            runtime::get_named_arg::<Option<u64>>(keys::AUCTION_TIMER_EXTENSION).unwrap_or(5 * 60 * 1000)
+        ),
+        (keys::MINIMUM_BID_STEP, runtime::get_named_arg::<Option<U512>>(keys::MINIMUM_BID_STEP)),
+        (keys::MARKETPLACE_COMMISSION, accounts::MARKETPLACE_COMMISSION / 10),
+        (keys::MARKETPLACE_ACCOUNT, marketplace_account)
+    )
+}
+
+fn legacy_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::BID,
+        vec![
+            Parameter::new(keys::BID, CLType::U512),
+            Parameter::new(keys::BID_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::INIT,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+    if runtime::get_key(keys::AUCTION_PURSE).is_none() {
+        runtime::put_key(keys::AUCTION_PURSE, system::create_purse().into());
+        Bids::init();
+        storage::new_dictionary(keys::EVENTS).unwrap_or_revert();
+    }
+}
+
+// Record a first bid of the calling account the way the first release stored it, keyed by account hash
+#[no_mangle]
+pub extern "C" fn bid() {
+    let bidder = runtime::get_caller();
+    let bid = runtime::get_named_arg::<U512>(keys::BID);
+    let bid_purse = runtime::get_named_arg::<URef>(keys::BID_PURSE);
+    system::transfer_from_purse_to_purse(bid_purse, read_named_key_uref(keys::AUCTION_PURSE), bid, None)
+        .unwrap_or_revert();
+
+    let bids = read_named_key_uref("bids_key");
+    let index = read_named_key_uref("bids_index");
+    let len = storage::dictionary_get::<Option<u64>>(index, "len")
+        .unwrap_or_revert()
+        .flatten()
+        .unwrap_or_default();
+    storage::dictionary_put(bids, &bidder.to_string(), Some((bid, false)));
+    storage::dictionary_put(index, &len.to_string(), Some(bidder));
+    storage::dictionary_put(index, &bidder.to_string(), Some(len));
+    storage::dictionary_put(index, "len", Some(len + 1));
+    write_named_key_value(keys::CURRENT_WINNER, Some(bidder));
+    write_named_key_value(keys::WINNING_BID, Some((bid, false)));
+}
+
+/**
+ * Install an English auction with the named keys and bids of the first release, for the tests to
+ * upgrade it with the current installer and migrate it. Released auctions were installed locked,
+ * this one is not so that it can take the upgrade.
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+    install::install(legacy_entry_points, legacy_english_auction_named_keys);
+}
@@ -0,0 +1,66 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_private_auction_core::functions;
+use casper_types::{
+    contracts::NamedKeys, CLType, ContractPackageHash, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, URef,
+};
+
+const PURSE: &str = "purse";
+const BROKEN: &str = "broken";
+
+// Purse the commission recipient is paid into
+#[no_mangle]
+pub extern "C" fn get_purse() {
+    let purse = runtime::get_key(PURSE)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(purse.into_add()).unwrap_or_revert());
+}
+
+/**
+ * Contract receiving commissions or beneficiary shares. A `broken` recipient hands out a URef that is
+ * addable but not a purse, so every transfer to it fails.
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let broken: bool = runtime::get_named_arg(BROKEN);
+    let purse: URef = if broken {
+        storage::new_uref(())
+    } else {
+        system::create_purse()
+    };
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(PURSE), purse.into());
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        functions::GET_PURSE,
+        vec![],
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(format!("{}_package_hash", contract_name)),
+        None,
+    );
+    let package_hash = runtime::get_key(&format!("{}_package_hash", contract_name))
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+    runtime::put_key(&format!("{}_contract_hash_wrapped", contract_name), storage::new_uref(contract_hash).into());
+    runtime::put_key(&format!("{}_package_hash_wrapped", contract_name), storage::new_uref(package_hash).into());
+}
@@ -0,0 +1,80 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_private_auction_core::{functions, keys};
+use casper_private_auction_core::credit::CreditLine;
+use casper_private_auction_core::info::AuctionInfo;
+use casper_private_auction_core::registry::AuctionRecord;
+use casper_private_auction_core::status::AuctionStatus;
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes}, runtime_args, ApiError, CLTyped, ContractPackageHash, Key, RuntimeArgs,
+};
+
+const PACKAGE_HASH: &str = "package_hash";
+const ENTRY_POINT: &str = "entry_point";
+
+// Call a view and keep its result in the calling account, under `{entry_point}_result`
+fn store<T: CLTyped + FromBytes + ToBytes>(package_hash: ContractPackageHash, entry_point: &str, args: RuntimeArgs) {
+    let result: T = runtime::call_versioned_contract(package_hash, None, entry_point, args);
+    runtime::put_key(&format!("{}_result", entry_point), storage::new_uref(result).into());
+}
+
+fn forward<T: CLTyped + FromBytes + ToBytes>(args: &mut RuntimeArgs, name: &str) {
+    args.insert(name, runtime::get_named_arg::<T>(name)).unwrap_or_revert();
+}
+
+fn page_args() -> RuntimeArgs {
+    let mut args = RuntimeArgs::new();
+    forward::<u32>(&mut args, keys::OFFSET);
+    forward::<u32>(&mut args, keys::LIMIT);
+    args
+}
+
+/**
+ * Session code reading a view of the factory or of an auction. View results cannot be read back
+ * from a deploy, so the tests run this and query the stored result instead.
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+    let package_hash = ContractPackageHash::new(
+        runtime::get_named_arg::<Key>(PACKAGE_HASH)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let entry_point: String = runtime::get_named_arg(ENTRY_POINT);
+    match entry_point.as_str() {
+        functions::GET_AUCTION_COUNT => store::<u32>(package_hash, &entry_point, runtime_args! {}),
+        functions::GET_AUCTIONS => store::<Vec<AuctionRecord>>(package_hash, &entry_point, page_args()),
+        functions::GET_AUCTIONS_BY_SELLER => {
+            let mut args = page_args();
+            forward::<Key>(&mut args, keys::SELLER);
+            store::<Vec<AuctionRecord>>(package_hash, &entry_point, args)
+        }
+        functions::GET_AUCTIONS_BY_STATUS => {
+            let mut args = page_args();
+            forward::<AuctionStatus>(&mut args, keys::STATUS);
+            store::<Vec<AuctionRecord>>(package_hash, &entry_point, args)
+        }
+        functions::GET_AUCTIONS_BY_TOKEN => {
+            let mut args = page_args();
+            forward::<Key>(&mut args, keys::TOKEN_PACKAGE_HASH);
+            forward::<String>(&mut args, keys::TOKEN_ID);
+            store::<Vec<AuctionRecord>>(package_hash, &entry_point, args)
+        }
+        functions::GET_CREDIT => {
+            let mut args = RuntimeArgs::new();
+            forward::<Key>(&mut args, keys::ACCOUNT);
+            store::<CreditLine>(package_hash, &entry_point, args)
+        }
+        functions::GET_AUCTION_INFO => store::<AuctionInfo>(package_hash, &entry_point, runtime_args! {}),
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
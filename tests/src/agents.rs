@@ -0,0 +1,68 @@
+use casper_types::{Key, U512};
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn approve_bidding_agent() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, bob, _) = auction.contract.accounts;
+
+    // Approving an agent leaves bids from session code as they are
+    let agent = Key::Hash(auction.contract.synth.1.value());
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.update_bidding_agents(&market, vec![agent], true, now + 500);
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(bob)));
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn approve_bidding_agent_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    let agent = Key::Hash(auction.contract.synth.1.value());
+    auction.contract.update_bidding_agents(&bob, vec![agent], true, now + 500);
+}
+
+#[test]
+#[should_panic = "User(80)"]
+fn approve_bidding_agent_not_contract() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.update_bidding_agents(&market, vec![Key::Account(bob)], true, now + 500);
+}
+
+#[test]
+fn agent_bid_refunded_to_refund_purse() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_mode(Some(0), None);
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, _, ali, bob, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    let (agent, refunds) = auction.contract.deploy_bidding_agent("dao_agent", U512::from(100_000));
+    auction.contract.update_bidding_agents(&market, vec![Key::Hash(agent.value())], true, now + 500);
+
+    // The contract bidder takes part under its own key
+    let dao = Key::Hash([7u8; 32]);
+    auction.contract.agent_bid(&ali, agent, dao, U512::from(30000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner(), (Some(dao), Some((U512::from(30000), false))));
+    let bid = auction.contract.get_events().pop().unwrap();
+    assert_eq!(bid["account"], dao.to_formatted_string());
+
+    // Outbid, its bid is refunded to the purse the agent designated at settlement
+    auction.bid(&bob, U512::from(40000), now + 1100);
+    auction.finalize(&admin, now + 5000);
+    assert!(auction.contract.is_settled());
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(bob)));
+    assert_eq!(auction.contract.get_purse_balance(refunds), U512::from(30000));
+}
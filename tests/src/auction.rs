@@ -6,13 +6,15 @@ use casper_engine_test_support::{
 };
 use casper_types::{
     account::AccountHash, bytesrepr::FromBytes, CLTyped, ContractHash, ContractPackageHash,
-    Key, runtime_args, RuntimeArgs, U512, U256,
+    Key, runtime_args, RuntimeArgs, URef, U512, U256,
 };
 use cep47::TokenId;
 use maplit::btreemap;
 use casper_private_auction_core::accounts::MARKETPLACE_ACCOUNT;
 
+use casper_private_auction_core::info::AuctionInfo;
 use casper_private_auction_core::keys;
+use casper_private_auction_core::status::AuctionStatus;
 
 use crate::{
     utils::{deploy, DeploySource, fund_account, query, query_dictionary_item, create_account},
@@ -38,8 +40,14 @@ pub trait BaseAuctionArgs {
     fn set_beneficiary(&mut self, account: &AccountHash);
     fn set_token_contract_hash(&mut self, hash: &ContractPackageHash);
     fn set_kyc_package_hash(&mut self, hash: &ContractPackageHash);
+    fn set_kyc_mode(&mut self, mode: Option<u8>, level: Option<U256>);
+    fn set_kyc_recheck(&mut self, recheck: Option<bool>);
+    fn set_settlement_timeout(&mut self, timeout: Option<u64>);
     fn set_synth_package_hash(&mut self, hash: &ContractPackageHash);
+    fn set_marketplace_settings(&mut self, hash: &ContractPackageHash);
     fn set_token_id(&mut self, token_id: &String);
+    fn set_marketplace(&mut self, account: Option<AccountHash>, commission: Option<u32>);
+    fn set_beneficiaries(&mut self, beneficiaries: Option<BTreeMap<AccountHash, u32>>);
     fn get_nft_commission(&self) -> u32;
     fn get_wasm(&self) -> String;
 }
@@ -50,6 +58,7 @@ pub struct AuctionContract {
     pub nft: (ContractHash, ContractPackageHash),
     pub kyc: (ContractHash, ContractPackageHash),
     pub synth: (ContractHash, ContractPackageHash),
+    pub factory: (ContractHash, ContractPackageHash),
     pub accounts: (AccountHash, AccountHash, AccountHash, AccountHash, AccountHash, AccountHash),
 }
 
@@ -90,6 +99,9 @@ impl AuctionContract {
 
         let (nft_hash, nft_package) = Self::deploy_nft(&mut builder, &admin, kyc_package);
 
+        // The factory holds the marketplace settings installed auctions are validated against
+        let (factory_hash, factory_package) = Self::deploy_factory(&mut builder, &admin);
+
         let token_meta = btreemap! {
             "origin".to_string() => "fire".to_string()
         };
@@ -116,6 +128,7 @@ impl AuctionContract {
         auction_args.set_token_contract_hash(&nft_package);
         auction_args.set_kyc_package_hash(&kyc_package);
         auction_args.set_synth_package_hash(&synth_package);
+        auction_args.set_marketplace_settings(&factory_package);
         auction_args.set_token_id(&token_id);
 
         let (auction_hash, auction_package) =
@@ -127,10 +140,23 @@ impl AuctionContract {
             nft: (nft_hash, nft_package),
             kyc: (kyc_hash, kyc_package),
             synth: (synth_hash, synth_package),
+            factory: (factory_hash, factory_package),
             accounts: (admin, market, artist, ali, bob, dan),
         }
     }
 
+    // Run the installer again under the same name, adding a new contract version to the auction package
+    pub fn upgrade(&mut self, wasm: String) {
+        let admin = self.accounts.0;
+        let (auction_hash, auction_package) = Self::deploy_auction(
+            wasm,
+            &mut self.builder,
+            &admin,
+            runtime_args! { keys::NAME => "test".to_string(), keys::UPGRADE => true },
+        );
+        self.auction_contract = (auction_hash, auction_package);
+    }
+
     pub fn transfer_funds(&mut self, account: &AccountHash, amount: U512) {
         self.builder.exec(fund_account(&account, amount)).expect_success().commit();
     }
@@ -249,6 +275,106 @@ impl AuctionContract {
         (contract_hash, contract_package)
     }
 
+    pub fn deploy_factory(
+        builder: &mut InMemoryWasmTestBuilder,
+        admin: &AccountHash,
+    ) -> (ContractHash, ContractPackageHash) {
+        deploy(
+            builder,
+            admin,
+            &DeploySource::Code(PathBuf::from("auction-factory.wasm")),
+            runtime_args! {},
+            true,
+            None,
+        );
+        let contract_hash: ContractHash = query(
+            builder,
+            Key::Account(*admin),
+            &["auction_factory_contract_hash_wrapped".to_string()],
+        );
+        let contract_package = builder
+            .get_contract(contract_hash)
+            .expect("should be contract.")
+            .contract_package_hash();
+        (contract_hash, contract_package)
+    }
+
+    // Contract recipient of payouts, a broken one cannot be paid
+    pub fn deploy_payout_recipient(&mut self, name: &str, broken: bool) -> ContractPackageHash {
+        let admin = self.accounts.0;
+        deploy(
+            &mut self.builder,
+            &admin,
+            &DeploySource::Code(PathBuf::from("payout-recipient.wasm")),
+            runtime_args! {
+                "contract_name" => name.to_string(),
+                "broken" => broken,
+            },
+            true,
+            None,
+        );
+        query(
+            &self.builder,
+            Key::Account(admin),
+            &[format!("{}_package_hash_wrapped", name)],
+        )
+    }
+
+    /// Install a bidding agent holding `funds` from the admin, returns its package and the purse its refunds go to
+    pub fn deploy_bidding_agent(&mut self, name: &str, funds: U512) -> (ContractPackageHash, URef) {
+        let admin = self.accounts.0;
+        deploy(
+            &mut self.builder,
+            &admin,
+            &DeploySource::Code(PathBuf::from("bidding-agent.wasm")),
+            runtime_args! {
+                "contract_name" => name.to_string(),
+                "amount" => funds,
+            },
+            true,
+            None,
+        );
+        let agent_hash: ContractHash = query(
+            &self.builder,
+            Key::Account(admin),
+            &[format!("{}_contract_hash_wrapped", name)],
+        );
+        let refunds = *self
+            .builder
+            .get_contract(agent_hash)
+            .expect("should be contract.")
+            .named_keys()
+            .get("refunds")
+            .expect("should have a refunds purse.")
+            .as_uref()
+            .expect("should be uref.");
+        let package_hash = query(
+            &self.builder,
+            Key::Account(admin),
+            &[format!("{}_package_hash_wrapped", name)],
+        );
+        (package_hash, refunds)
+    }
+
+    // Bid through an agent for the contract bidder
+    pub fn agent_bid(&mut self, caller: &AccountHash, agent: ContractPackageHash, bidder: Key, bid: U512, time: u64) {
+        deploy(
+            &mut self.builder,
+            caller,
+            &DeploySource::ByPackageHash {
+                package_hash: agent,
+                method: "bid".to_string(),
+            },
+            runtime_args! {
+                keys::AUCTION => Key::Hash(self.auction_contract.1.value()),
+                keys::BIDDER => bidder,
+                keys::BID => bid,
+            },
+            true,
+            Some(time),
+        );
+    }
+
     pub fn deploy_auction(
         wasm: String,
         builder: &mut InMemoryWasmTestBuilder,
@@ -324,10 +450,53 @@ impl AuctionContract {
         );
     }
 
+    // Change the commission of an actor in the token's table, as the NFT admin
+    pub fn update_token_commission(&mut self, sender: &AccountHash, actor: &str, account: &str, rate: &str) {
+        let args = runtime_args! {
+            "token_id" => self.get_token_id(),
+            "property" => actor.to_string(),
+            "account" => account.to_string(),
+            "mode" => "UPDATE".to_string(),
+            "value" => rate.to_string(),
+        };
+        deploy(
+            &mut self.builder,
+            sender,
+            &DeploySource::ByPackageHash {
+                package_hash: self.nft.1,
+                method: "update_token_commission".to_string(),
+            },
+            args,
+            true,
+            None,
+        );
+    }
+
     // pub fn add_kyc_token(&mut self, recipient: &AccountHash) {
     //     Self::add_kyc(&mut self.builder, &self.kyc_package, &self.admin, recipient)
     // }
 
+    // Mark the KYC token of an account as no longer active
+    pub fn revoke_kyc(&mut self, account: &AccountHash) {
+        let mut token_meta = BTreeMap::new();
+        token_meta.insert("status".to_string(), "revoked".to_string());
+        let admin = self.accounts.0;
+        deploy(
+            &mut self.builder,
+            &admin,
+            &DeploySource::ByPackageHash {
+                package_hash: self.kyc.1,
+                method: "update_token_meta".to_string(),
+            },
+            runtime_args! {
+                "token_id" => account.to_string(),
+                "token_meta" => token_meta,
+            },
+            true,
+            None,
+        );
+    }
+
     pub fn add_kyc(
         builder: &mut InMemoryWasmTestBuilder,
         kyc_package: &ContractPackageHash,
@@ -385,13 +554,39 @@ impl AuctionContract {
     }
 
     pub fn bid(&mut self, bidder: &AccountHash, bid: U512, block_time: u64) {
-        let session_code = PathBuf::from("delta-bid-purse.wasm");
+        self.bid_purse(bidder, "delta", bid, block_time)
+    }
+
+    // Transfer the whole bid, for contracts without the `get_bid` view the delta is read from
+    pub fn full_bid(&mut self, bidder: &AccountHash, bid: U512, block_time: u64) {
+        self.bid_purse(bidder, "full", bid, block_time)
+    }
+
+    pub fn proxy_bid(&mut self, bidder: &AccountHash, max_bid: U512, block_time: u64) {
+        self.bid_purse(bidder, "proxy", max_bid, block_time)
+    }
+
+    pub fn buy_now(&mut self, bidder: &AccountHash, max_price: U512, block_time: u64) {
+        self.bid_purse(bidder, "buy_now", max_price, block_time)
+    }
+
+    pub fn fund_synthetic(&mut self, caller: &AccountHash, amount: U512, block_time: u64) {
+        self.bid_purse(caller, "fund", amount, block_time)
+    }
+
+    pub fn cancel_with_penalty(&mut self, seller: &AccountHash, max_penalty: U512, block_time: u64) {
+        self.bid_purse(seller, "penalty", max_penalty, block_time)
+    }
+
+    fn bid_purse(&mut self, caller: &AccountHash, mode: &str, amount: U512, block_time: u64) {
+        let session_code = PathBuf::from("bid-purse.wasm");
         deploy(
             &mut self.builder,
-            bidder,
+            caller,
             &DeploySource::Code(session_code),
             runtime_args! {
-                "amount" => bid,
+                "amount" => amount,
+                "mode" => mode.to_string(),
                 "purse_name" => "my_auction_purse",
                 "auction_contract" => self.auction_contract.0
             },
@@ -407,6 +602,12 @@ impl AuctionContract {
         }, time)
     }
 
+    pub fn cancel_synthetic_bid(&mut self, caller: &AccountHash, bidder: &AccountHash, time: u64) {
+        self.call(caller, "cancel_synthetic_bid", runtime_args! {
+            "bidder" => Key::Account(*bidder)
+        }, time)
+    }
+
     pub fn cancel_auction(&mut self, caller: &AccountHash, time: u64) {
         self.call(caller, "cancel_auction", runtime_args! {}, time)
     }
@@ -415,46 +616,137 @@ impl AuctionContract {
         self.call(caller, "approve", runtime_args! {}, time)
     }
 
-    pub fn reject(&mut self, caller: &AccountHash, time: u64) {
-        self.call(caller, "reject", runtime_args! {}, time)
+    pub fn reject(&mut self, caller: &AccountHash, offer_next: bool, time: u64) {
+        self.call(caller, "reject", runtime_args! {
+            "offer_next" => offer_next
+        }, time)
+    }
+
+    pub fn refresh_commissions(&mut self, caller: &AccountHash, time: u64) {
+        self.call(caller, "refresh_commissions", runtime_args! {}, time)
+    }
+
+    pub fn resume_settlement(&mut self, caller: &AccountHash, time: u64) {
+        self.call(caller, "resume_settlement", runtime_args! {}, time)
+    }
+
+    pub fn force_cancel(&mut self, caller: &AccountHash, reason: u8, time: u64) {
+        self.call(caller, "force_cancel", runtime_args! { "reason" => reason }, time)
+    }
+
+    pub fn update_gating_list(&mut self, caller: &AccountHash, accounts: Vec<Key>, listed: bool, time: u64) {
+        self.call(caller, "update_gating_list", runtime_args! { "accounts" => accounts, "listed" => listed }, time)
+    }
+
+    pub fn update_bidding_agents(&mut self, caller: &AccountHash, agents: Vec<Key>, approved: bool, time: u64) {
+        self.call(caller, "update_bidding_agents", runtime_args! { "agents" => agents, "approved" => approved }, time)
+    }
+
+    pub fn migrate(&mut self, caller: &AccountHash, time: u64) {
+        self.call(caller, "migrate", runtime_args! {}, time)
+    }
+
+    pub fn pause(&mut self, caller: &AccountHash, time: u64) {
+        self.call(caller, "pause", runtime_args! {}, time)
+    }
+
+    pub fn unpause(&mut self, caller: &AccountHash, extend_end_time: bool, time: u64) {
+        self.call(caller, "unpause", runtime_args! { "extend_end_time" => extend_end_time }, time)
+    }
+
+    pub fn redirect_payout(&mut self, caller: &AccountHash, holding_account: Key, time: u64) {
+        self.call(caller, "redirect_payout", runtime_args! { "holding_account" => holding_account }, time)
+    }
+
+    pub fn get_auction_info(&mut self, caller: &AccountHash, time: u64) -> AuctionInfo {
+        let package_hash = self.auction_contract.1;
+        self.read_view(caller, package_hash, "get_auction_info", runtime_args! {}, Some(time))
+    }
+
+    pub fn get_fees(&mut self, caller: &AccountHash, time: u64) -> (AccountHash, u32) {
+        let package_hash = self.auction_contract.1;
+        self.read_view(caller, package_hash, "get_fees", runtime_args! {}, Some(time))
     }
 
     /// Observers
     pub fn is_live(&self) -> bool {
-        let status: u8 = self.query_auction_contract(keys::STATUS);
-        status == casper_private_auction_core::data::AUCTION_LIVE
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Live
     }
 
     pub fn is_cancelled(&self) -> bool {
-        let status: u8 = self.query_auction_contract(keys::STATUS);
-        status == casper_private_auction_core::data::AUCTION_CANCELLED
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Cancelled
+    }
+
+    pub fn is_voided(&self) -> bool {
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Voided
+    }
+
+    pub fn is_settling(&self) -> bool {
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Settling
     }
 
     pub fn is_pending_settle(&self) -> bool {
-        let status: u8 = self.query_auction_contract(keys::STATUS);
-        status == casper_private_auction_core::data::AUCTION_PENDING_SETTLE
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::PendingSettle
     }
 
     pub fn is_rejected(&self) -> bool {
-        let status: u8 = self.query_auction_contract(keys::STATUS);
-        status == casper_private_auction_core::data::AUCTION_REJECTED
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Rejected
     }
 
     pub fn is_settled(&self) -> bool {
-        let status: u8 = self.query_auction_contract(keys::STATUS);
-        status == casper_private_auction_core::data::AUCTION_SETTLED
+        let status: AuctionStatus = self.query_auction_contract(keys::STATUS);
+        status == AuctionStatus::Settled
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.query_auction_contract(keys::START)
+    }
+
+    pub fn get_cancel(&self) -> Option<u64> {
+        self.query_auction_contract(keys::CANCEL)
     }
 
     pub fn get_end(&self) -> u64 {
         self.query_auction_contract(keys::END)
     }
 
-    pub fn get_current_winner(&self) -> (Option<AccountHash>, Option<(U512, bool)>) {
-        let winner: Option<AccountHash> = self.query_auction_contract(keys::CURRENT_WINNER);
+    pub fn get_current_winner(&self) -> (Option<Key>, Option<(U512, bool)>) {
+        let winner: Option<Key> = self.query_auction_contract(keys::CURRENT_WINNER);
         let bid: Option<(U512, bool)> = self.query_auction_contract(keys::WINNING_BID);
         (winner, bid)
     }
 
+    pub fn get_token_id(&self) -> TokenId {
+        self.query_auction_contract(keys::TOKEN_ID)
+    }
+
+    // Token the account holds first, if any
+    pub fn get_owned_token(&self, account: &AccountHash) -> Option<TokenId> {
+        Self::get_token_by_index(&self.builder, &self.nft.0, account, U256::zero())
+    }
+
+    pub fn get_state_version(&self) -> u32 {
+        self.query_auction_contract(keys::STATE_VERSION)
+    }
+
+    pub fn has_auction_key(&self, name: &str) -> bool {
+        self.builder
+            .get_contract(self.auction_contract.0)
+            .expect("should be contract.")
+            .named_keys()
+            .contains_key(name)
+    }
+
+    pub fn get_commissions(&self) -> BTreeMap<Key, u32> {
+        self.query_auction_contract(keys::COMMISSIONS)
+    }
+
     pub fn get_event(&self, index: u32) -> BTreeMap<String, String> {
         self.query_dictionary_value(
             Key::Hash(self.auction_contract.0.value()),
@@ -476,6 +768,28 @@ impl AuctionContract {
         self.query_auction_contract("auction_events_count")
     }
 
+    /// Read a view of a contract package through the view reader session, which stores its result in the caller.
+    pub fn read_view<T: CLTyped + FromBytes>(
+        &mut self,
+        caller: &AccountHash,
+        package_hash: ContractPackageHash,
+        entry_point: &str,
+        mut args: RuntimeArgs,
+        block_time: Option<u64>,
+    ) -> T {
+        args.insert("package_hash", Key::Hash(package_hash.value())).unwrap();
+        args.insert("entry_point", entry_point.to_string()).unwrap();
+        deploy(
+            &mut self.builder,
+            caller,
+            &DeploySource::Code(PathBuf::from("view-reader.wasm")),
+            args,
+            true,
+            block_time,
+        );
+        query(&self.builder, Key::Account(*caller), &[format!("{}_result", entry_point)])
+    }
+
     /// Wrapper function for calling an entrypoint on the contract with the access rights of the deployer.
     pub fn call(&mut self, caller: &AccountHash, method: &str, args: RuntimeArgs, time: u64) {
         deploy(
@@ -523,11 +837,8 @@ impl AuctionContract {
     fn query_auction_contract<T: CLTyped + FromBytes>(&self, name: &str) -> T {
         query(
             &self.builder,
-            Key::Account(self.accounts.0),
-            &[
-                "test_auction_contract_hash".to_string(),
-                name.to_string(),
-            ],
+            Key::Hash(self.auction_contract.0.value()),
+            &[name.to_string()],
         )
     }
 
@@ -540,6 +851,10 @@ impl AuctionContract {
         self.builder.get_purse_balance(account.main_purse())
     }
 
+    pub fn get_purse_balance(&self, purse: URef) -> U512 {
+        self.builder.get_purse_balance(purse)
+    }
+
     pub fn get_balances(&self) -> (U512, U512, U512, U512, U512, U512) {
         let admin = self.get_account_balance(&self.accounts.0);
         let market = self.get_account_balance(&self.accounts.1);
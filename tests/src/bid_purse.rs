@@ -0,0 +1,41 @@
+use casper_types::{Key, U512};
+use crate::dutch_args::AuctionArgBuilder;
+use crate::dutch_auction::DutchAuctionContract;
+use crate::utils;
+
+#[test]
+fn buy_now() {
+    let now = utils::get_now_u64();
+    let auction_args = AuctionArgBuilder::base(
+        now,
+        U512::from(30000),
+        U512::from(20000),
+        100
+    );
+    let mut auction = DutchAuctionContract::deploy(auction_args);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    // Pays the current price, whatever is left of the cap stays in the main purse
+    auction.buy_now(&bob, U512::from(50000), now + 1000);
+    let (winner, bid) = auction.contract.get_current_winner();
+    assert_eq!(winner, Some(Key::Account(bob)));
+    assert!(!bid.unwrap().1);
+    assert!(bid.unwrap().0 <= U512::from(30000));
+    assert!(auction.contract.is_settled());
+}
+
+#[test]
+#[should_panic = "User(104)"]
+fn buy_now_above_max_price() {
+    let now = utils::get_now_u64();
+    let auction_args = AuctionArgBuilder::base(
+        now,
+        U512::from(30000),
+        U512::from(20000),
+        100
+    );
+    let mut auction = DutchAuctionContract::deploy(auction_args);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.buy_now(&bob, U512::from(10000), now + 1000);
+}
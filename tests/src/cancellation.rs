@@ -0,0 +1,80 @@
+use casper_types::U512;
+use crate::auction::{AuctionContract, BaseAuctionArgs};
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn force_cancel_with_bids() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, market, _, ali, bob, _) = auction.contract.accounts;
+    let token_id = auction.contract.get_token_id();
+
+    auction.bid(&ali, U512::from(30000), now + 1000);
+    auction.bid(&bob, U512::from(40000), now + 1001);
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    let (_, _, _, ali_before, bob_before, _) = auction.contract.get_balances();
+    auction.contract.force_cancel(&market, 1, now + 1002);
+    assert!(auction.contract.is_voided());
+
+    // Both bids are refunded in full and the seller has the token back
+    let (_, _, _, ali_after, bob_after, _) = auction.contract.get_balances();
+    assert_eq!(ali_after - ali_before, U512::from(30000));
+    assert_eq!(bob_after - bob_before, U512::from(40000));
+    assert_eq!(auction.contract.get_owned_token(&admin), Some(token_id));
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn force_cancel_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, _, _, _, _, _) = auction.contract.accounts;
+
+    auction.contract.force_cancel(&admin, 1, now + 1000);
+}
+
+#[test]
+#[should_panic = "User(71)"]
+fn deploy_auction_seller_penalty_above_full() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_seller_cancellation_penalty(Some(10001));
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+fn cancel_with_penalty_compensates_funded_bids() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_seller_cancellation_penalty(Some(1000));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, _, ali, bob, dan) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    let (kyc, synth) = (auction.contract.kyc.1, auction.contract.synth.1);
+    AuctionContract::add_kyc(&mut auction.contract.builder, &kyc, &admin, &dan);
+    AuctionContract::add_synth(&mut auction.contract.builder, &synth, &admin, &dan);
+
+    auction.bid(&ali, U512::from(30000), now + 1000);
+    auction.bid(&bob, U512::from(40000), now + 1100);
+    auction.synthetic_bid(&market, &dan, U512::from(50000), now + 1200);
+    let (_, _, _, ali_before, bob_before, dan_before) = auction.contract.get_balances();
+
+    // 10% of the highest bid, shared by the 70000 of funded bids
+    auction.contract.cancel_with_penalty(&admin, U512::from(5000), now + 2000);
+    assert!(auction.contract.is_cancelled());
+    // Cancelled is only reported once the refunds and compensations are paid
+    let events = auction.contract.get_events();
+    let event_types: Vec<&str> = events.iter().rev().take(2).map(|event| event["event_type"].as_str()).collect();
+    assert_eq!(event_types, vec!["Cancelled", "CancelledWithPenalty"]);
+
+    // 5000 * 3/7 and 5000 * 4/7 rounded down, the dust goes to the highest funded bid
+    let (_, _, _, ali_after, bob_after, dan_after) = auction.contract.get_balances();
+    assert_eq!(ali_after - ali_before, U512::from(30000 + 2142));
+    assert_eq!(bob_after - bob_before, U512::from(40000 + 2858));
+    // The synthetic bidder had nothing at stake
+    assert_eq!(dan_after, dan_before);
+}
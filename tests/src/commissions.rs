@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use casper_types::{account::AccountHash, ApiError, ContractPackageHash, Key, PublicKey, SecretKey, U512};
+use casper_private_auction_core::commissions::{parse_commissions, parse_rate, parse_recipient, CommissionRecipient};
+use casper_private_auction_core::error::AuctionError;
+
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+// Errors are compared by the code they revert with
+fn code<T>(result: Result<T, AuctionError>) -> Option<ApiError> {
+    result.err().map(ApiError::from)
+}
+
+fn public_key() -> PublicKey {
+    PublicKey::from(&SecretKey::ed25519_from_bytes([7u8; 32]).unwrap())
+}
+
+#[test]
+fn parse_account_recipients() {
+    let account = AccountHash::new([3u8; 32]);
+    let hex = hex::encode(account.value());
+    for recipient in [account.to_formatted_string(), format!("Key::Account({})", hex), hex] {
+        assert_eq!(parse_recipient(&recipient).ok(), Some(CommissionRecipient::Account(account)));
+    }
+}
+
+#[test]
+fn parse_public_key_recipient() {
+    let public_key = public_key();
+    assert_eq!(
+        parse_recipient(&public_key.to_hex()).ok(),
+        Some(CommissionRecipient::Account(public_key.to_account_hash())),
+    );
+}
+
+#[test]
+fn parse_contract_recipients() {
+    let package = ContractPackageHash::new([5u8; 32]);
+    let hex = hex::encode(package.value());
+    for recipient in [format!("hash-{}", hex), format!("Key::Hash({})", hex), package.to_formatted_string()] {
+        assert_eq!(parse_recipient(&recipient).ok(), Some(CommissionRecipient::Contract(package)));
+    }
+}
+
+#[test]
+fn parse_recipient_errors() {
+    let contract_error = Some(ApiError::User(63));
+    assert_eq!(code(parse_recipient("hash-not-hex")), contract_error);
+    assert_eq!(code(parse_recipient("Key::Hash(1234)")), contract_error);
+    // Anything else has to be a public key
+    assert_eq!(code(parse_recipient("artist")), Some(ApiError::User(64)));
+    assert_eq!(code(parse_recipient("01abcd")), Some(ApiError::User(64)));
+}
+
+#[test]
+fn parse_rates() {
+    assert_eq!(parse_rate("100").ok(), Some(1000));
+    assert_eq!(parse_rate("2.5").ok(), Some(25));
+    assert_eq!(parse_rate("0.1").ok(), Some(1));
+    assert_eq!(parse_rate("7.").ok(), Some(70));
+    assert_eq!(code(parse_rate("2.55")), Some(ApiError::User(65)));
+    assert_eq!(code(parse_rate("0.125")), Some(ApiError::User(65)));
+}
+
+#[test]
+fn parse_commission_table() {
+    let artist = AccountHash::new([3u8; 32]);
+    let public_key = public_key();
+    let package = ContractPackageHash::new([5u8; 32]);
+    let mut commissions = BTreeMap::new();
+    commissions.insert("artist_account".to_string(), artist.to_formatted_string());
+    commissions.insert("artist_rate".to_string(), "100".to_string());
+    commissions.insert("broker_account".to_string(), public_key.to_hex());
+    commissions.insert("broker_rate".to_string(), "2.5".to_string());
+    commissions.insert("dao_account".to_string(), format!("hash-{}", hex::encode(package.value())));
+    commissions.insert("dao_rate".to_string(), "10".to_string());
+
+    let parsed = parse_commissions(&commissions).ok().unwrap();
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed.get(&CommissionRecipient::Account(artist)), Some(&1000));
+    assert_eq!(parsed.get(&CommissionRecipient::Account(public_key.to_account_hash())), Some(&25));
+    assert_eq!(parsed.get(&CommissionRecipient::Contract(package)), Some(&100));
+}
+
+#[test]
+fn parse_commission_table_errors() {
+    let mut commissions = BTreeMap::new();
+    commissions.insert("artist_account".to_string(), "hash-zz".to_string());
+    commissions.insert("artist_rate".to_string(), "100".to_string());
+    assert_eq!(code(parse_commissions(&commissions)), Some(ApiError::User(63)));
+
+    commissions.insert("artist_account".to_string(), "not-a-key".to_string());
+    assert_eq!(code(parse_commissions(&commissions)), Some(ApiError::User(64)));
+
+    commissions.insert("artist_account".to_string(), AccountHash::new([3u8; 32]).to_formatted_string());
+    commissions.insert("artist_rate".to_string(), "1.25".to_string());
+    assert_eq!(code(parse_commissions(&commissions)), Some(ApiError::User(65)));
+}
+
+#[test]
+fn commissions_snapshot() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, _, artist, ali, bob, _) = auction.contract.accounts;
+
+    // 100 thousandths of the proceeds are stored as 1000 basis points
+    let commissions = auction.contract.get_commissions();
+    assert_eq!(commissions.get(&Key::Account(artist)), Some(&1000));
+
+    // The token's table changes while the auction runs
+    auction.bid(&bob, U512::from(10001), now + 1000);
+    auction.contract.update_token_commission(&admin, "artist", &artist.to_formatted_string(), "300");
+    assert_eq!(auction.contract.get_commissions(), commissions);
+
+    // Settlement pays the artist from the snapshot, 10% rather than 30% of the 9751 left after the marketplace fee
+    let (_, _, artist_before, _, _, _) = auction.contract.get_balances();
+    auction.finalize(&ali, now + 5000);
+    let (_, _, artist_after, _, _, _) = auction.contract.get_balances();
+    assert_eq!(artist_after - artist_before, U512::from(975));
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn refresh_commissions_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.refresh_commissions(&bob, now + 1000);
+}
@@ -1,4 +1,4 @@
-use casper_types::U512;
+use casper_types::{Key, U512};
 use casper_private_auction_core::accounts::MARKETPLACE_COMMISSION;
 use crate::auction::BaseAuctionArgs;
 use crate::dutch_args::AuctionArgBuilder;
@@ -155,7 +155,7 @@ fn bid() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), bob);
+        assert_eq!(winner.unwrap(), Key::Account(bob));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(!bid.unwrap().1);
@@ -220,7 +220,7 @@ fn synth_bid() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -249,7 +249,7 @@ fn synth_bid_approve_no_permission() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -278,7 +278,7 @@ fn synth_bid_approve() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -315,7 +315,7 @@ fn synth_bid_reject_no_permission() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -344,7 +344,7 @@ fn synth_bid_reject() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -384,7 +384,7 @@ fn bid_after_settle() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), bob);
+        assert_eq!(winner.unwrap(), Key::Account(bob));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(!bid.unwrap().1);
@@ -417,7 +417,7 @@ fn synth_bid_after_settle() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
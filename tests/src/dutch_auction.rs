@@ -32,6 +32,10 @@ impl DutchAuctionContract {
         self.contract.bid(bidder, bid, block_time)
     }
 
+    pub fn buy_now(&mut self, bidder: &AccountHash, max_price: U512, block_time: u64) {
+        self.contract.buy_now(bidder, max_price, block_time)
+    }
+
     pub fn synthetic_bid(&mut self, admin: &AccountHash, bidder: &AccountHash, bid: U512, block_time: u64) {
         self.contract.synthetic_bid(admin, bidder, bid, block_time)
     }
@@ -45,6 +49,6 @@ impl DutchAuctionContract {
     }
 
     pub fn reject(&mut self, caller: &AccountHash, time: u64) {
-        self.contract.reject(caller, time)
+        self.contract.reject(caller, false, time)
     }
 }
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+
 use casper_types::{
     account::AccountHash, ContractPackageHash, Key, runtime_args,
-    RuntimeArgs, U512,
+    RuntimeArgs, U256, U512,
 };
 
 use casper_private_auction_core::keys;
@@ -16,8 +18,16 @@ pub struct AuctionArgBuilder {
     token_contract_hash: ContractPackageHash,
     // into Key
     kyc_package_hash: ContractPackageHash,
+    // false passes an account key instead, as a seller without a KYC contract would
+    kyc_contract: bool,
+    kyc_mode: Option<u8>,
+    kyc_level: Option<U256>,
+    kyc_recheck: Option<bool>,
+    settlement_timeout: Option<u64>,
     // into Key
     synth_package_hash: ContractPackageHash,
+    // into Key
+    marketplace_settings: ContractPackageHash,
     token_id: String,
     start_time: u64,
     cancel_time: Option<u64>,
@@ -26,8 +36,14 @@ pub struct AuctionArgBuilder {
     reserve_price: U512,
     bidder_cap: Option<u64>,
     minimum_bid_step: Option<U512>,
+    minimum_bid_increments: Option<BTreeMap<U512, u32>>,
     auction_timer_extension: Option<u64>,
     nft_commission: u32,
+    marketplace_account: Option<AccountHash>,
+    marketplace_commission: Option<u32>,
+    beneficiaries: Option<BTreeMap<AccountHash, u32>>,
+    seller_cancellation_penalty: Option<u32>,
+    installer: String,
 }
 
 impl AuctionArgBuilder {
@@ -41,7 +57,13 @@ impl AuctionArgBuilder {
             beneficiary_account: account.clone(),
             token_contract_hash: ContractPackageHash::new([0u8; 32]),
             kyc_package_hash: ContractPackageHash::new([0u8; 32]),
+            kyc_contract: true,
+            kyc_mode: None,
+            kyc_level: None,
+            kyc_recheck: None,
+            settlement_timeout: None,
             synth_package_hash: ContractPackageHash::new([0u8; 32]),
+            marketplace_settings: ContractPackageHash::new([0u8; 32]),
             token_id: "token_id".to_string(),
             start_time,
             cancel_time: Some(start_time + 3500),
@@ -50,28 +72,76 @@ impl AuctionArgBuilder {
             reserve_price: reserve_price.clone(),
             bidder_cap: None,
             minimum_bid_step: None,
+            minimum_bid_increments: None,
             auction_timer_extension: None,
             nft_commission,
+            marketplace_account: None,
+            marketplace_commission: None,
+            beneficiaries: None,
+            seller_cancellation_penalty: None,
+            installer: "english-auction-installer.wasm".to_string(),
         }
     }
 }
 
+impl AuctionArgBuilder {
+    pub fn set_seller_cancellation_penalty(&mut self, penalty: Option<u32>) {
+        self.seller_cancellation_penalty = penalty;
+    }
+
+    pub fn set_minimum_bid_increments(&mut self, increments: Option<BTreeMap<U512, u32>>) {
+        self.minimum_bid_increments = increments;
+    }
+
+    pub fn set_installer(&mut self, installer: &str) {
+        self.installer = installer.to_string();
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn without_kyc_contract(&mut self) {
+        self.kyc_contract = false;
+    }
+}
+
 impl BaseAuctionArgs for AuctionArgBuilder {
     fn build(&self) -> RuntimeArgs {
         runtime_args! {
             keys::BENEFICIARY_ACCOUNT=>Key::Account(self.beneficiary_account),
             keys::TOKEN_PACKAGE_HASH=>Key::Hash(self.token_contract_hash.value()),
-            keys::KYC_PACKAGE_HASH=>Key::Hash(self.kyc_package_hash.value()),
+            keys::KYC_PACKAGE_HASH => if self.kyc_contract {
+                Key::Hash(self.kyc_package_hash.value())
+            } else {
+                Key::Account(self.beneficiary_account)
+            },
+            keys::KYC_MODE => self.kyc_mode,
+            keys::KYC_LEVEL => self.kyc_level,
+            keys::KYC_RECHECK => self.kyc_recheck,
+            keys::SETTLEMENT_TIMEOUT => self.settlement_timeout,
             keys::SYNTHETIC_PACKAGE_HASH=>Key::Hash(self.synth_package_hash.value()),
+            keys::MARKETPLACE_SETTINGS => Key::Hash(self.marketplace_settings.value()),
             keys::TOKEN_ID=>self.token_id.to_owned(),
             keys::START => self.start_time,
             keys::CANCEL => self.cancel_time,
             keys::END => self.end_time,
             keys::NAME => self.name.clone(),
+            keys::UPGRADE => false,
             keys::RESERVE_PRICE => self.reserve_price,
             keys::BIDDER_NUMBER_CAP => self.bidder_cap,
             keys::MINIMUM_BID_STEP => self.minimum_bid_step,
+            keys::MINIMUM_BID_INCREMENTS => self.minimum_bid_increments.clone(),
             keys::AUCTION_TIMER_EXTENSION => self.auction_timer_extension,
+            keys::MARKETPLACE_ACCOUNT => self.marketplace_account.map(Key::Account),
+            keys::MARKETPLACE_COMMISSION => self.marketplace_commission,
+            keys::BENEFICIARIES => self.beneficiaries.as_ref().map(|beneficiaries| {
+                beneficiaries
+                    .iter()
+                    .map(|(account, share)| (Key::Account(*account), *share))
+                    .collect::<BTreeMap<Key, u32>>()
+            }),
+            keys::SELLER_CANCELLATION_PENALTY => self.seller_cancellation_penalty,
         }
     }
 
@@ -99,20 +169,46 @@ impl BaseAuctionArgs for AuctionArgBuilder {
         self.kyc_package_hash = hash.clone();
     }
 
+    fn set_kyc_mode(&mut self, mode: Option<u8>, level: Option<U256>) {
+        self.kyc_mode = mode;
+        self.kyc_level = level;
+    }
+
+    fn set_kyc_recheck(&mut self, recheck: Option<bool>) {
+        self.kyc_recheck = recheck;
+    }
+
+    fn set_settlement_timeout(&mut self, timeout: Option<u64>) {
+        self.settlement_timeout = timeout;
+    }
+
     fn set_synth_package_hash(&mut self, hash: &ContractPackageHash){
         self.synth_package_hash = hash.clone();
     }
 
+    fn set_marketplace_settings(&mut self, hash: &ContractPackageHash) {
+        self.marketplace_settings = *hash;
+    }
+
     fn set_token_id(&mut self, token_id: &String){
         self.token_id = token_id.clone();
     }
 
+    fn set_marketplace(&mut self, account: Option<AccountHash>, commission: Option<u32>) {
+        self.marketplace_account = account;
+        self.marketplace_commission = commission;
+    }
+
+    fn set_beneficiaries(&mut self, beneficiaries: Option<BTreeMap<AccountHash, u32>>) {
+        self.beneficiaries = beneficiaries;
+    }
+
     fn get_nft_commission(&self) -> u32 {
         self.nft_commission
     }
 
     fn get_wasm(&self) -> String {
-        "english-auction-installer.wasm".to_string()
+        self.installer.clone()
     }
 }
 
@@ -124,7 +220,13 @@ impl Default for AuctionArgBuilder {
             beneficiary_account: account.clone(),
             token_contract_hash: ContractPackageHash::new([0u8; 32]),
             kyc_package_hash: ContractPackageHash::new([0u8; 32]),
+            kyc_contract: true,
+            kyc_mode: None,
+            kyc_level: None,
+            kyc_recheck: None,
+            settlement_timeout: None,
             synth_package_hash: ContractPackageHash::new([0u8; 32]),
+            marketplace_settings: ContractPackageHash::new([0u8; 32]),
             token_id: "token_id".to_string(),
             start_time: now + 500,
             cancel_time: Some(now + 3500),
@@ -133,8 +235,14 @@ impl Default for AuctionArgBuilder {
             reserve_price: U512::from(10000),
             bidder_cap: Some(5_u64),
             minimum_bid_step: Some(U512::from(10)),
+            minimum_bid_increments: None,
             auction_timer_extension: Some(500),
             nft_commission: 100_u32,
+            marketplace_account: None,
+            marketplace_commission: None,
+            beneficiaries: None,
+            seller_cancellation_penalty: None,
+            installer: "english-auction-installer.wasm".to_string(),
         }
     }
 }
@@ -30,6 +30,10 @@ impl EnglishAuctionContract {
         self.contract.bid(bidder, bid, block_time)
     }
 
+    pub fn proxy_bid(&mut self, bidder: &AccountHash, max_bid: U512, block_time: u64) {
+        self.contract.proxy_bid(bidder, max_bid, block_time)
+    }
+
     pub fn synthetic_bid(&mut self, admin: &AccountHash, bidder: &AccountHash, bid: U512, block_time: u64) {
         self.contract.synthetic_bid(admin, bidder, bid, block_time)
     }
@@ -48,11 +52,19 @@ impl EnglishAuctionContract {
         self.contract.cancel_auction(caller, time)
     }
 
+    pub fn finalize(&mut self, caller: &AccountHash, time: u64) {
+        self.contract.call(caller, "finalize", runtime_args! {}, time)
+    }
+
     pub fn approve(&mut self, caller: &AccountHash, time: u64) {
         self.contract.approve(caller, time)
     }
 
     pub fn reject(&mut self, caller: &AccountHash, time: u64) {
-        self.contract.reject(caller, time)
+        self.contract.reject(caller, false, time)
+    }
+
+    pub fn reject_to_next_bid(&mut self, caller: &AccountHash, time: u64) {
+        self.contract.reject(caller, true, time)
     }
 }
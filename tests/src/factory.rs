@@ -0,0 +1,174 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U512};
+use casper_private_auction_core::credit::CreditLine;
+use casper_private_auction_core::keys;
+use casper_private_auction_core::status::{AuctionStatus, ContractType};
+
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::factory_contract::FactoryContract;
+use crate::utils;
+
+fn english_args(now: u64) -> AuctionArgBuilder {
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_cancel_time(Some(now + 3500));
+    auction_args.set_end_time(now + 5000);
+    auction_args
+}
+
+#[test]
+fn create_and_list() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (admin, _, _, ali, bob, _) = factory.contract.accounts;
+
+    let token_id = factory.create_english_auction(&bob, english_args(now));
+    let auction = factory.contract.auction_contract.1;
+    assert_eq!(factory.get_auction_count(&ali), 1);
+
+    let by_seller = factory.get_auctions_by_seller(&ali, &bob);
+    assert_eq!(by_seller.len(), 1);
+    let record = &by_seller[0];
+    assert_eq!(record.auction, auction);
+    assert_eq!(record.contract_type, ContractType::EnglishAuction);
+    assert_eq!(record.status, AuctionStatus::Live);
+    assert_eq!(record.seller, Key::Account(bob));
+    assert_eq!(record.token, Some((factory.contract.nft.1, token_id.clone())));
+    assert!(factory.get_auctions_by_seller(&ali, &admin).is_empty());
+
+    let by_token = factory.get_auctions_by_token(&ali, &token_id);
+    assert_eq!(by_token, by_seller);
+    assert!(factory.get_auctions_by_token(&ali, "unknown").is_empty());
+
+    // The created auction knows its factory and holds the token
+    assert!(factory.contract.is_live());
+    assert_eq!(factory.contract.get_token_id(), token_id);
+    assert_eq!(factory.contract.get_owned_token(&bob), None);
+}
+
+#[test]
+fn create_keeps_dictionaries_in_auction() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, _, _, _, bob, _) = factory.contract.accounts;
+
+    factory.create_english_auction(&bob, english_args(now));
+    for name in [keys::EVENTS, keys::GATING_LIST, keys::BIDDING_AGENTS, keys::AGENT_BIDDERS, keys::PROXY_BIDS] {
+        assert!(factory.contract.has_auction_key(name), "{} missing", name);
+        assert!(!factory.has_factory_key(name), "{} created in the factory", name);
+    }
+}
+
+#[test]
+fn status_change_reaches_registry() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, _, _, ali, bob, dan) = factory.contract.accounts;
+
+    factory.create_english_auction(&bob, english_args(now));
+    factory.contract.bid(&ali, U512::from(30000), now + 1000);
+    factory.contract.call(&bob, "finalize", runtime_args! {}, now + 5001);
+    assert!(factory.contract.is_settled());
+
+    // The auction reports its new status to the registry, syncing it again changes nothing
+    assert!(factory.get_auctions_by_status(&dan, AuctionStatus::Live).is_empty());
+    factory.sync_status(&dan);
+    let settled = factory.get_auctions_by_status(&dan, AuctionStatus::Settled);
+    assert_eq!(settled.len(), 1);
+    assert_eq!(settled[0].auction, factory.contract.auction_contract.1);
+    assert_eq!(settled[0].status, AuctionStatus::Settled);
+    assert_eq!(factory.contract.get_owned_token(&ali), Some(factory.contract.get_token_id()));
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn update_status_not_auction() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, _, _, ali, _, _) = factory.contract.accounts;
+
+    // Only created contracts report their status
+    factory.call(&ali, "update_status", runtime_args! { "status" => AuctionStatus::Cancelled });
+}
+
+#[test]
+#[should_panic = "User(74)"]
+fn sync_status_unregistered() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, _, _, ali, _, _) = factory.contract.accounts;
+
+    // The auction installed without the factory is not in its registry
+    factory.sync_status(&ali);
+}
+
+fn credit(limit: Option<u64>, reserved: u64, settled: u64) -> CreditLine {
+    CreditLine {
+        limit: limit.map(U512::from),
+        reserved: U512::from(reserved),
+        settled: U512::from(settled),
+    }
+}
+
+#[test]
+fn credit_reserved_and_released() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (admin, market, _, ali, bob, dan) = factory.contract.accounts;
+    factory.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    factory.create_english_auction(&bob, english_args(now));
+    factory.contract.synthetic_bid(&market, &ali, U512::from(20000), now + 1000);
+    assert_eq!(factory.get_credit(&dan, &ali), credit(None, 20000, 0));
+
+    // A higher bid replaces the reservation
+    factory.contract.synthetic_bid(&market, &ali, U512::from(30000), now + 1100);
+    assert_eq!(factory.get_credit(&dan, &ali), credit(None, 30000, 0));
+
+    // Outbid, the synthetic leader gets its credit back
+    factory.contract.bid(&admin, U512::from(40000), now + 1200);
+    assert_eq!(factory.get_credit(&dan, &ali), credit(None, 0, 0));
+    assert_eq!(factory.contract.get_current_winner(), (Some(Key::Account(admin)), Some((U512::from(40000), false))));
+
+    // So does a cancelled bid
+    factory.contract.synthetic_bid(&market, &ali, U512::from(50000), now + 1300);
+    assert_eq!(factory.get_credit(&dan, &ali), credit(None, 50000, 0));
+    factory.contract.cancel_synthetic_bid(&market, &ali, now + 1400);
+    assert_eq!(factory.get_credit(&dan, &ali), credit(None, 0, 0));
+}
+
+#[test]
+fn credit_settled_and_repaid() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (admin, market, _, ali, bob, dan) = factory.contract.accounts;
+    factory.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    factory.create_english_auction(&bob, english_args(now));
+    factory.set_credit_limit(&market, &ali, Some(U512::from(60000)));
+    factory.contract.synthetic_bid(&market, &ali, U512::from(50000), now + 1000);
+    factory.contract.call(&admin, "finalize", runtime_args! {}, now + 5001);
+    assert!(factory.contract.is_pending_settle());
+    assert_eq!(factory.get_credit(&dan, &ali), credit(Some(60000), 50000, 0));
+
+    // The approved win turns the reservation into an obligation
+    factory.contract.approve(&market, now + 5500);
+    assert!(factory.contract.is_settled());
+    assert_eq!(factory.get_credit(&dan, &ali), credit(Some(60000), 0, 50000));
+
+    factory.repay_credit(&market, &ali, U512::from(20000));
+    assert_eq!(factory.get_credit(&dan, &ali), credit(Some(60000), 0, 30000));
+}
+
+#[test]
+#[should_panic = "User(78)"]
+fn credit_limit_exceeded() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, market, _, ali, bob, _) = factory.contract.accounts;
+    factory.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    factory.create_english_auction(&bob, english_args(now));
+    factory.set_credit_limit(&market, &ali, Some(U512::from(40000)));
+    factory.contract.synthetic_bid(&market, &ali, U512::from(50000), now + 1000);
+}
@@ -0,0 +1,171 @@
+use casper_types::{
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U512,
+};
+use maplit::btreemap;
+
+use casper_private_auction_core::credit::CreditLine;
+use casper_private_auction_core::keys;
+use casper_private_auction_core::registry::AuctionRecord;
+use casper_private_auction_core::status::AuctionStatus;
+
+use crate::auction::{AuctionContract, BaseAuctionArgs};
+use crate::english_args::AuctionArgBuilder;
+use crate::utils::{deploy, DeploySource};
+
+pub struct FactoryContract {
+    pub contract: AuctionContract,
+    pub factory: (ContractHash, ContractPackageHash),
+}
+
+impl FactoryContract {
+    /// Use the factory installed next to the contracts of an auction installed the usual way,
+    /// the created auctions reuse its NFT, KYC and synthetic contracts and accounts.
+    pub fn deploy(start_time: u64) -> Self {
+        let mut auction_args = AuctionArgBuilder::default();
+        auction_args.set_start_time(start_time);
+        let contract = AuctionContract::deploy(&mut auction_args);
+        let factory = contract.factory;
+        Self {
+            contract,
+            factory,
+        }
+    }
+
+    /// Mint a new token to the seller and put it up in an English auction created by the factory.
+    /// The harness then drives the created auction.
+    pub fn create_english_auction(&mut self, seller: &AccountHash, mut auction_args: AuctionArgBuilder) -> String {
+        let admin = self.contract.accounts.0;
+        let artist = self.contract.accounts.2;
+        let commissions = btreemap! {
+            "artist_account".to_string() => artist.to_formatted_string(),
+            "artist_rate".to_string() => auction_args.get_nft_commission().to_string(),
+        };
+        AuctionContract::mint_nft(
+            &mut self.contract.builder,
+            &self.contract.nft.1,
+            &Key::Account(*seller),
+            btreemap! { "origin".to_string() => "fire".to_string() },
+            &admin,
+            commissions,
+        );
+        let token_id = self.contract.get_owned_token(seller).expect("seller should own the token.");
+
+        auction_args.set_beneficiary(seller);
+        auction_args.set_token_contract_hash(&self.contract.nft.1);
+        auction_args.set_kyc_package_hash(&self.contract.kyc.1);
+        auction_args.set_synth_package_hash(&self.contract.synth.1);
+        auction_args.set_token_id(&token_id);
+        self.call(seller, "create_english_auction", auction_args.build());
+
+        let count = self.get_auction_count(seller);
+        let record = self.get_auctions(seller, count - 1, 1).pop().expect("should be registered.");
+        let auction_hash = self
+            .contract
+            .builder
+            .get_contract_package(record.auction)
+            .expect("should be contract package.")
+            .current_contract_hash()
+            .expect("should have a version.");
+        self.contract.auction_contract = (auction_hash, record.auction);
+        token_id
+    }
+
+    pub fn call(&mut self, caller: &AccountHash, method: &str, args: RuntimeArgs) {
+        deploy(
+            &mut self.contract.builder,
+            caller,
+            &DeploySource::ByPackageHash {
+                package_hash: self.factory.1,
+                method: method.to_string(),
+            },
+            args,
+            true,
+            None,
+        );
+    }
+
+    pub fn sync_status(&mut self, caller: &AccountHash) {
+        let auction = Key::Hash(self.contract.auction_contract.1.value());
+        self.call(caller, "sync_status", runtime_args! { keys::AUCTION => auction })
+    }
+
+    pub fn set_marketplace_settings(&mut self, caller: &AccountHash, account: &AccountHash, max_commission: u32) {
+        self.call(caller, "set_marketplace_settings", runtime_args! {
+            keys::MARKETPLACE_ACCOUNT => Key::Account(*account),
+            keys::MAX_MARKETPLACE_COMMISSION => max_commission,
+        })
+    }
+
+    pub fn set_credit_limit(&mut self, caller: &AccountHash, account: &AccountHash, limit: Option<U512>) {
+        self.call(caller, "set_credit_limit", runtime_args! {
+            keys::ACCOUNT => Key::Account(*account),
+            keys::CREDIT_LIMIT => limit,
+        })
+    }
+
+    pub fn repay_credit(&mut self, caller: &AccountHash, account: &AccountHash, amount: U512) {
+        self.call(caller, "repay_credit", runtime_args! {
+            keys::ACCOUNT => Key::Account(*account),
+            keys::AMOUNT => amount,
+        })
+    }
+
+    pub fn has_factory_key(&self, name: &str) -> bool {
+        self.contract
+            .builder
+            .get_contract(self.factory.0)
+            .expect("should be contract.")
+            .named_keys()
+            .contains_key(name)
+    }
+
+    /// Views, read through the view reader
+    pub fn get_auction_count(&mut self, caller: &AccountHash) -> u32 {
+        let factory = self.factory.1;
+        self.contract.read_view(caller, factory, "get_auction_count", runtime_args! {}, None)
+    }
+
+    pub fn get_auctions(&mut self, caller: &AccountHash, offset: u32, limit: u32) -> Vec<AuctionRecord> {
+        let factory = self.factory.1;
+        self.contract.read_view(caller, factory, "get_auctions", runtime_args! {
+            keys::OFFSET => offset,
+            keys::LIMIT => limit,
+        }, None)
+    }
+
+    pub fn get_auctions_by_seller(&mut self, caller: &AccountHash, seller: &AccountHash) -> Vec<AuctionRecord> {
+        let factory = self.factory.1;
+        self.contract.read_view(caller, factory, "get_auctions_by_seller", runtime_args! {
+            keys::SELLER => Key::Account(*seller),
+            keys::OFFSET => 0_u32,
+            keys::LIMIT => 10_u32,
+        }, None)
+    }
+
+    pub fn get_auctions_by_status(&mut self, caller: &AccountHash, status: AuctionStatus) -> Vec<AuctionRecord> {
+        let factory = self.factory.1;
+        self.contract.read_view(caller, factory, "get_auctions_by_status", runtime_args! {
+            keys::STATUS => status,
+            keys::OFFSET => 0_u32,
+            keys::LIMIT => 10_u32,
+        }, None)
+    }
+
+    pub fn get_auctions_by_token(&mut self, caller: &AccountHash, token_id: &str) -> Vec<AuctionRecord> {
+        let factory = self.factory.1;
+        let token_package_hash = Key::Hash(self.contract.nft.1.value());
+        self.contract.read_view(caller, factory, "get_auctions_by_token", runtime_args! {
+            keys::TOKEN_PACKAGE_HASH => token_package_hash,
+            keys::TOKEN_ID => token_id.to_string(),
+            keys::OFFSET => 0_u32,
+            keys::LIMIT => 10_u32,
+        }, None)
+    }
+
+    pub fn get_credit(&mut self, caller: &AccountHash, account: &AccountHash) -> CreditLine {
+        let factory = self.factory.1;
+        self.contract.read_view(caller, factory, "get_credit", runtime_args! {
+            keys::ACCOUNT => Key::Account(*account),
+        }, None)
+    }
+}
@@ -171,6 +171,33 @@ fn gift_token_and_claim_non_admin() {
     gift_contract.claim(&ali, &ali, token_id.clone(), now);
 }
 
+#[test]
+fn gift_token_count() {
+    let mut gift_contract = GiftContract::deploy();
+    let (admin, tim, ali, _bob, dan) = gift_contract.accounts;
+    let now = utils::get_now_u64();
+
+    let tim_token = gift_contract.get_token_by_index(&tim, U256::from(0)).unwrap();
+    let ali_token = gift_contract.get_token_by_index(&ali, U256::from(0)).unwrap();
+    assert_eq!(gift_contract.get_gift_count(), 0);
+
+    gift_contract.gift(&admin, &tim, tim_token.clone(), now);
+    gift_contract.gift(&admin, &ali, ali_token.clone(), now);
+    assert_eq!(gift_contract.get_gift_count(), 2);
+    assert_eq!(gift_contract.get_gift_by_index(0), Some(tim_token.clone()));
+    assert_eq!(gift_contract.get_gift_by_index(1), Some(ali_token.clone()));
+
+    // Claiming the first gift moves the last one into its place
+    gift_contract.claim(&admin, &dan, tim_token, now);
+    assert_eq!(gift_contract.get_gift_count(), 1);
+    assert_eq!(gift_contract.get_gift_by_index(0), Some(ali_token.clone()));
+    assert_eq!(gift_contract.get_gift_by_index(1), None);
+
+    gift_contract.cancel(&admin, ali_token, now);
+    assert_eq!(gift_contract.get_gift_count(), 0);
+    assert_eq!(gift_contract.get_gift_by_index(0), None);
+}
+
 //
 // #[test]
 // #[should_panic = "User(9)"]
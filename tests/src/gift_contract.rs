@@ -9,6 +9,7 @@ use casper_types::bytesrepr::FromBytes;
 use cep47::TokenId;
 use maplit::btreemap;
 use casper_private_auction_core::accounts::GIFT_ACCOUNT;
+use casper_private_auction_core::keys;
 
 use crate::{
     utils::{deploy, DeploySource, fund_account, query, query_dictionary_item, create_account},
@@ -199,7 +200,8 @@ impl GiftContract {
             admin,
             &deploy_code,
             runtime_args! {
-                "contract_name" => "test"
+                "contract_name" => "test",
+                keys::UPGRADE => false
             },
             true,
             None,
@@ -329,6 +331,31 @@ impl GiftContract {
         }, time)
     }
 
+    pub fn get_gift_count(&self) -> u32 {
+        query(
+            &self.builder,
+            Key::Account(self.accounts.0),
+            &[
+                "test_contract_hash".to_string(),
+                keys::TOKEN_COUNT.to_string(),
+            ],
+        )
+    }
+
+    pub fn get_gift_by_index(&self, index: u32) -> Option<String> {
+        query_dictionary_item(&self.builder,
+                              Key::Hash(self.gift_contract.0.value()),
+                              Some(keys::TOKENS_BY_INDEX.to_string()),
+                              index.to_string()
+        )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("Wrong type in query result.")
+    }
+
     pub fn owner_of(&self, token_id: TokenId) -> Option<Key> {
         self.query_dictionary("owners", token_id)
     }
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+use casper_types::{Key, U512};
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn bid_with_tiered_increments() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    // +10% below 30000, +2% above
+    let mut increments = BTreeMap::new();
+    increments.insert(U512::zero(), 1000);
+    increments.insert(U512::from(30000), 200);
+    auction_args.set_minimum_bid_increments(Some(increments));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, _, _, ali, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(20000), now + 1000);
+    auction.bid(&ali, U512::from(22000), now + 1100);
+    auction.bid(&bob, U512::from(40000), now + 1200);
+    auction.bid(&ali, U512::from(40800), now + 1300);
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(ali)), Some((U512::from(40800), false))));
+}
+
+#[test]
+#[should_panic = "User(3)"]
+fn bid_below_percentage_increment() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    let mut increments = BTreeMap::new();
+    increments.insert(U512::zero(), 1000);
+    auction_args.set_minimum_bid_increments(Some(increments));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, _, _, ali, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(20000), now + 1000);
+    auction.bid(&ali, U512::from(21999), now + 1100);
+}
+
+#[test]
+#[should_panic = "User(83)"]
+fn deploy_auction_increments_not_from_zero() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    let mut increments = BTreeMap::new();
+    increments.insert(U512::from(10000), 500);
+    auction_args.set_minimum_bid_increments(Some(increments));
+    EnglishAuctionContract::deploy(auction_args);
+}
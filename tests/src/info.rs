@@ -0,0 +1,41 @@
+use casper_types::{Key, U512};
+use casper_private_auction_core::status::{AuctionStatus, ContractType};
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn auction_info_view() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, market, _, ali, bob, _) = auction.contract.accounts;
+
+    let info = auction.contract.get_auction_info(&ali, now + 600);
+    assert_eq!(info.contract_type, ContractType::EnglishAuction);
+    assert_eq!(info.status, AuctionStatus::Live);
+    assert_eq!(info.seller, Key::Account(admin));
+    assert_eq!(info.winner, None);
+    assert_eq!(info.bid_count, 0);
+
+    auction.bid(&ali, U512::from(30000), now + 900);
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    let info = auction.contract.get_auction_info(&ali, now + 1500);
+    assert!(!info.paused);
+    assert_eq!(info.winner, Some(Key::Account(bob)));
+    assert_eq!(info.winning_bid, Some(U512::from(40000)));
+    assert_eq!(info.bid_count, 2);
+    assert_eq!(info.reserve_price, Some(U512::from(10000)));
+    // Prices of the other formats
+    assert_eq!(info.start_price, None);
+    assert_eq!(info.swap_price, None);
+    assert_eq!(info.current_price, None);
+    assert_eq!(info.minimum_bid_step, Some(U512::from(10)));
+    assert_eq!(info.end_time, auction.contract.get_end());
+
+    // Still readable while paused
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.pause(&market, now + 2000);
+    let info = auction.contract.get_auction_info(&ali, now + 2500);
+    assert!(info.paused);
+    assert_eq!(info.status, AuctionStatus::Live);
+    assert_eq!(info.winner, Some(Key::Account(bob)));
+}
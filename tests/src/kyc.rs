@@ -0,0 +1,116 @@
+use casper_types::{Key, U512};
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+#[should_panic = "User(18)"]
+fn bid_not_on_allowlist() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_mode(Some(2), None);
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(40000), now + 1000);
+}
+
+#[test]
+fn bid_on_allowlist() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_mode(Some(2), None);
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.update_gating_list(&admin, vec![Key::Account(bob)], true, now + 500);
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(bob)));
+}
+
+#[test]
+#[should_panic = "User(76)"]
+fn deploy_auction_invalid_kyc_mode() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_mode(Some(4), None);
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+#[should_panic = "User(76)"]
+fn deploy_auction_no_kyc_contract_without_mode() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.without_kyc_contract();
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+fn bid_open_without_kyc_contract() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.without_kyc_contract();
+    auction_args.set_kyc_mode(Some(0), None);
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, _, _, _, _, dan) = auction.contract.accounts;
+
+    // Open bidding has to be asked for, dan has no KYC token
+    auction.bid(&dan, U512::from(30000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(dan)));
+}
+
+#[test]
+fn finalize_skips_disqualified_winner() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_mode(Some(2), None);
+    auction_args.set_kyc_recheck(Some(true));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, _, _, ali, bob, _) = auction.contract.accounts;
+
+    auction.contract.update_gating_list(&admin, vec![Key::Account(ali), Key::Account(bob)], true, now + 500);
+    auction.bid(&ali, U512::from(40000), now + 1000);
+    auction.bid(&bob, U512::from(50000), now + 1500);
+    auction.contract.update_gating_list(&admin, vec![Key::Account(bob)], false, now + 2000);
+    auction.finalize(&admin, now + 5000);
+
+    assert!(auction.contract.is_settled());
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(ali)), Some((U512::from(40000), false))));
+    let disqualified = auction
+        .contract
+        .get_events()
+        .into_iter()
+        .find(|event| event.get("event_type").map(String::as_str) == Some("WinnerDisqualified"))
+        .unwrap();
+    assert_eq!(disqualified.get("account"), Some(&Key::Account(bob).to_formatted_string()));
+}
+
+#[test]
+fn approve_rejects_when_every_bidder_disqualified() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_kyc_recheck(Some(true));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, _, ali, _, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.synthetic_bid(&market, &ali, U512::from(50000), now + 1000);
+    auction.finalize(&admin, now + 5000);
+    assert!(auction.contract.is_pending_settle());
+
+    // The only bidder loses its KYC before the win is approved, the token goes back to the seller
+    auction.contract.revoke_kyc(&ali);
+    auction.approve(&market, now + 6000);
+    assert!(auction.contract.is_rejected());
+    assert_eq!(auction.contract.get_current_winner().0, None);
+    assert_eq!(auction.contract.get_owned_token(&admin), Some(auction.contract.get_token_id()));
+}
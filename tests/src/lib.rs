@@ -10,6 +10,8 @@ pub mod english_auction;
 pub mod swap_args;
 pub mod swap_auction;
 
+pub mod factory_contract;
+
 pub mod utils;
 
 // #[cfg(test)]
@@ -22,4 +24,49 @@ pub mod utils;
 // pub mod swap;
 
 #[cfg(test)]
-pub mod gift;
\ No newline at end of file
+pub mod gift;
+
+#[cfg(test)]
+pub mod commissions;
+
+#[cfg(test)]
+pub mod factory;
+
+#[cfg(test)]
+pub mod marketplace;
+
+#[cfg(test)]
+pub mod settlement;
+
+#[cfg(test)]
+pub mod pause;
+
+#[cfg(test)]
+pub mod cancellation;
+
+#[cfg(test)]
+pub mod upgrade;
+
+#[cfg(test)]
+pub mod info;
+
+#[cfg(test)]
+pub mod status;
+
+#[cfg(test)]
+pub mod kyc;
+
+#[cfg(test)]
+pub mod synthetic;
+
+#[cfg(test)]
+pub mod bid_purse;
+
+#[cfg(test)]
+pub mod agents;
+
+#[cfg(test)]
+pub mod proxy;
+
+#[cfg(test)]
+pub mod increments;
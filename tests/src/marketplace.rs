@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+use casper_types::U512;
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::factory_contract::FactoryContract;
+use crate::utils::{self, deploy, DeploySource};
+
+#[test]
+#[should_panic = "User(62)"]
+fn deploy_auction_commission_above_max() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_marketplace(None, Some(1001));
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+#[should_panic = "User(62)"]
+fn deploy_auction_zero_commission() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_marketplace(None, Some(0));
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+#[should_panic = "User(61)"]
+fn deploy_auction_foreign_marketplace_account() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    // Only the marketplace set by the admin can receive the fee
+    auction_args.set_marketplace(Some(utils::create_account()), None);
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+fn create_with_admin_settings() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (_, market, _, ali, bob, _) = factory.contract.accounts;
+    factory.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    // Above the compiled default, allowed once the admin raises the maximum
+    factory.set_marketplace_settings(&market, &market, 1500);
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_marketplace(None, Some(1200));
+    factory.create_english_auction(&bob, auction_args);
+    assert_eq!(factory.contract.get_fees(&ali, now + 500), (market, 1200));
+}
+
+#[test]
+#[should_panic = "User(62)"]
+fn install_above_admin_max_commission() {
+    let now = utils::get_now_u64();
+    let mut factory = FactoryContract::deploy(now);
+    let (admin, market, _, _, _, _) = factory.contract.accounts;
+    factory.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    // Installers read the maximum from the factory as well
+    factory.set_marketplace_settings(&market, &market, 300);
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_name("second");
+    auction_args.set_token_contract_hash(&factory.contract.nft.1);
+    auction_args.set_kyc_package_hash(&factory.contract.kyc.1);
+    auction_args.set_synth_package_hash(&factory.contract.synth.1);
+    auction_args.set_marketplace_settings(&factory.factory.1);
+    auction_args.set_token_id(&factory.contract.get_token_id());
+    auction_args.set_beneficiary(&admin);
+    auction_args.set_marketplace(None, Some(400));
+    deploy(
+        &mut factory.contract.builder,
+        &admin,
+        &DeploySource::Code(PathBuf::from("english-auction-installer.wasm")),
+        auction_args.build(),
+        true,
+        None,
+    );
+}
@@ -0,0 +1,63 @@
+use casper_types::U512;
+use crate::dutch_args::AuctionArgBuilder;
+use crate::dutch_auction::DutchAuctionContract;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+#[should_panic = "User(68)"]
+fn bid_while_paused() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.pause(&market, now + 1000);
+    auction.bid(&bob, U512::from(40000), now + 1100);
+}
+
+#[test]
+fn unpause_extends_end_time() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, _, _) = auction.contract.accounts;
+    let (start, cancel, end) = (auction.contract.get_start(), auction.contract.get_cancel(), auction.contract.get_end());
+
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.pause(&market, now + 1000);
+    auction.contract.unpause(&market, true, now + 1500);
+    assert_eq!(auction.contract.get_end(), end + 500);
+    assert_eq!(auction.contract.get_cancel(), cancel.map(|cancel| cancel + 500));
+    assert_eq!(auction.contract.get_start(), start);
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn pause_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.pause(&bob, now + 1000);
+}
+
+#[test]
+fn unpause_shifts_price_curve() {
+    let now = utils::get_now_u64();
+    let auction_args = AuctionArgBuilder::base(
+        now,
+        U512::from(30000),
+        U512::from(20000),
+        100
+    );
+    let mut auction = DutchAuctionContract::deploy(auction_args);
+    let (_, market, _, _, _, _) = auction.contract.accounts;
+    let (start, end) = (auction.contract.get_start(), auction.contract.get_end());
+
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.contract.pause(&market, now + 1000);
+    auction.contract.unpause(&market, true, now + 2000);
+    // Start and end move together, the price does not drop for the paused time
+    assert_eq!(auction.contract.get_start(), start + 1000);
+    assert_eq!(auction.contract.get_end(), end + 1000);
+}
@@ -0,0 +1,22 @@
+use casper_types::{Key, U512};
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn proxy_bid_outbids_competitor() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, ali, bob, _) = auction.contract.accounts;
+
+    // The first proxy bid opens at the reserve price
+    auction.proxy_bid(&ali, U512::from(50000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(ali)), Some((U512::from(10000), false))));
+
+    // The proxy answers one step above the competing bid
+    auction.bid(&bob, U512::from(20000), now + 1100);
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(ali)), Some((U512::from(20010), false))));
+
+    // Above the maximum the competitor takes the lead
+    auction.bid(&bob, U512::from(60000), now + 1200);
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(bob)), Some((U512::from(60000), false))));
+}
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet};
+use casper_types::{Key, U512};
+use crate::auction::BaseAuctionArgs;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn settlement_receipt_skips_zero_share() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    let (main, minor) = (utils::create_account(), utils::create_account());
+    let mut beneficiaries = BTreeMap::new();
+    beneficiaries.insert(main, 9999);
+    beneficiaries.insert(minor, 1);
+    auction_args.set_beneficiaries(Some(beneficiaries));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, artist, ali, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(10001), now + 1000);
+    let (admin_before, market_before, artist_before, _, _, _) = auction.contract.get_balances();
+    auction.finalize(&ali, now + 5000);
+    assert!(auction.contract.is_settled());
+
+    // 2.5% marketplace fee, 10% of the rest to the artist, the beneficiaries split the remaining 8776
+    let (admin_after, market_after, artist_after, _, _, _) = auction.contract.get_balances();
+    assert_eq!(market_after - market_before, U512::from(250));
+    assert_eq!(artist_after - artist_before, U512::from(975));
+    assert_eq!(auction.contract.get_account_balance(&main), U512::from(8775));
+    // The share of 0.01% rounds down to nothing and is skipped, the dust goes to the beneficiary account
+    assert!(auction.contract.builder.get_account(minor).is_none());
+    assert_eq!(admin_after - admin_before, U512::from(1));
+
+    let receipt = auction
+        .contract
+        .get_events()
+        .into_iter()
+        .find(|event| event["event_type"] == "SettlementReceipt")
+        .unwrap();
+    assert_eq!(receipt["account"], Key::Account(bob).to_formatted_string());
+    assert_eq!(receipt["bid"], "10001");
+    let payouts: BTreeSet<&str> = receipt["payouts"].split(',').collect();
+    let expected = [
+        format!("{}:250", Key::Account(market).to_formatted_string()),
+        format!("{}:975", Key::Account(artist).to_formatted_string()),
+        format!("{}:8775", Key::Account(main).to_formatted_string()),
+        format!("{}:1", Key::Account(admin).to_formatted_string()),
+    ];
+    assert_eq!(payouts, expected.iter().map(String::as_str).collect());
+    assert_eq!(receipt["skipped"], Key::Account(minor).to_formatted_string());
+}
+
+#[test]
+fn beneficiaries_split_proceeds() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    let (first, second) = (utils::create_account(), utils::create_account());
+    let mut beneficiaries = BTreeMap::new();
+    beneficiaries.insert(first, 3333);
+    beneficiaries.insert(second, 6667);
+    auction_args.set_beneficiaries(Some(beneficiaries));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, _, _, ali, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(10001), now + 1000);
+    let (admin_before, _, _, _, _, _) = auction.contract.get_balances();
+    auction.finalize(&ali, now + 5000);
+    assert!(auction.contract.is_settled());
+
+    // 8776 is left after the marketplace fee and the artist commission,
+    // 33.33% is 2925.04 and 66.67% is 5850.96, both rounded down
+    assert_eq!(auction.contract.get_account_balance(&first), U512::from(2925));
+    assert_eq!(auction.contract.get_account_balance(&second), U512::from(5850));
+    // The mote of rounding dust goes to the beneficiary account, which is not part of the split
+    let (admin_after, _, _, _, _, _) = auction.contract.get_balances();
+    assert_eq!(admin_after - admin_before, U512::from(1));
+}
+
+#[test]
+#[should_panic = "User(66)"]
+fn deploy_auction_beneficiary_split_not_full() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    let mut beneficiaries = BTreeMap::new();
+    beneficiaries.insert(utils::create_account(), 6000);
+    beneficiaries.insert(utils::create_account(), 3000);
+    auction_args.set_beneficiaries(Some(beneficiaries));
+    EnglishAuctionContract::deploy(auction_args);
+}
+
+#[test]
+fn stalled_payout_redirected() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, market, _, ali, bob, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    // The artist commission now goes to a contract whose purse cannot be paid
+    let recipient = auction.contract.deploy_payout_recipient("broken_recipient", true);
+    let recipient_account = format!("hash-{}", hex::encode(recipient.value()));
+    auction.contract.update_token_commission(&admin, "artist", &recipient_account, "100");
+    auction.contract.refresh_commissions(&market, now + 500);
+
+    auction.bid(&bob, U512::from(10001), now + 1000);
+    auction.finalize(&ali, now + 5000);
+    assert!(auction.contract.is_settling());
+    let events = auction.contract.get_events();
+    let stalled = events.last().unwrap();
+    assert_eq!(stalled["event_type"], "SettlementStalled");
+    assert!(!events.iter().any(|event| event["event_type"] == "Settled"));
+
+    // Retrying does not help, the payout stalls again
+    auction.contract.resume_settlement(&ali, now + 5100);
+    assert!(auction.contract.is_settling());
+
+    // Redirected to a holding account the artist share is paid and the settlement carries on
+    let holding = utils::create_account();
+    auction.contract.redirect_payout(&market, Key::Account(holding), now + 5200);
+    assert!(auction.contract.is_settled());
+    assert_eq!(auction.contract.get_account_balance(&holding), U512::from(975));
+    let settled = auction.contract.get_events().pop().unwrap();
+    assert_eq!(settled["event_type"], "Settled");
+    assert_eq!(settled["account"], Key::Account(bob).to_formatted_string());
+}
+
+#[test]
+#[should_panic = "User(75)"]
+fn resume_settlement_not_settling() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.resume_settlement(&bob, now + 1000);
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn redirect_payout_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.redirect_payout(&bob, Key::Account(bob), now + 1000);
+}
@@ -0,0 +1,15 @@
+use casper_types::U512;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+#[should_panic = "User(75)"]
+fn approve_live_auction() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, bob, _) = auction.contract.accounts;
+
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    auction.approve(&market, now + 1500);
+}
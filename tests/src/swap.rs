@@ -1,4 +1,4 @@
-use casper_types::U512;
+use casper_types::{Key, U512};
 use casper_private_auction_core::accounts::MARKETPLACE_COMMISSION;
 use crate::swap_args::AuctionArgBuilder;
 use crate::swap_auction::SwapAuctionContract;
@@ -123,7 +123,7 @@ fn bid() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), bob);
+        assert_eq!(winner.unwrap(), Key::Account(bob));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(!bid.unwrap().1);
@@ -185,7 +185,7 @@ fn synth_bid() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -213,7 +213,7 @@ fn synth_bid_approve_no_permission() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -241,7 +241,7 @@ fn synth_bid_approve() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -277,7 +277,7 @@ fn synth_bid_reject_no_permission() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -305,7 +305,7 @@ fn synth_bid_reject() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -344,7 +344,7 @@ fn bid_after_settle() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), bob);
+        assert_eq!(winner.unwrap(), Key::Account(bob));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(!bid.unwrap().1);
@@ -376,7 +376,7 @@ fn synth_bid_after_settle() {
     {
         let (winner, bid) = auction.contract.get_current_winner();
         assert!(winner.is_some());
-        assert_eq!(winner.unwrap(), ali);
+        assert_eq!(winner.unwrap(), Key::Account(ali));
         assert!(bid.is_some());
         assert_eq!(bid.unwrap().0, bid_price);
         assert!(bid.unwrap().1);
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+
 use casper_types::{
     account::AccountHash, ContractPackageHash, Key, runtime_args,
-    RuntimeArgs, U512,
+    RuntimeArgs, U256, U512,
 };
 
 use casper_private_auction_core::keys;
@@ -16,14 +18,23 @@ pub struct AuctionArgBuilder {
     token_contract_hash: ContractPackageHash,
     // into Key
     kyc_package_hash: ContractPackageHash,
+    kyc_mode: Option<u8>,
+    kyc_level: Option<U256>,
+    kyc_recheck: Option<bool>,
+    settlement_timeout: Option<u64>,
     // into Key
     synth_package_hash: ContractPackageHash,
+    // into Key
+    marketplace_settings: ContractPackageHash,
     token_id: String,
     start_time: u64,
     end_time: u64,
     name: String,
     swap_price: U512,
     nft_commission: u32,
+    marketplace_account: Option<AccountHash>,
+    marketplace_commission: Option<u32>,
+    beneficiaries: Option<BTreeMap<AccountHash, u32>>,
 }
 
 impl AuctionArgBuilder {
@@ -38,13 +49,21 @@ impl AuctionArgBuilder {
             beneficiary_account: account.clone(),
             token_contract_hash: ContractPackageHash::new([0u8; 32]),
             kyc_package_hash: ContractPackageHash::new([0u8; 32]),
+            kyc_mode: None,
+            kyc_level: None,
+            kyc_recheck: None,
+            settlement_timeout: None,
             synth_package_hash: ContractPackageHash::new([0u8; 32]),
+            marketplace_settings: ContractPackageHash::new([0u8; 32]),
             token_id: "token_id".to_string(),
             start_time,
             end_time: start_time + 3500,
             name: "test".to_string(),
             swap_price,
             nft_commission,
+            marketplace_account: None,
+            marketplace_commission: None,
+            beneficiaries: None,
         }
     }
 }
@@ -55,12 +74,26 @@ impl BaseAuctionArgs for AuctionArgBuilder {
             keys::BENEFICIARY_ACCOUNT=>Key::Account(self.beneficiary_account),
             keys::TOKEN_PACKAGE_HASH=>Key::Hash(self.token_contract_hash.value()),
             keys::KYC_PACKAGE_HASH=>Key::Hash(self.kyc_package_hash.value()),
+            keys::KYC_MODE => self.kyc_mode,
+            keys::KYC_LEVEL => self.kyc_level,
+            keys::KYC_RECHECK => self.kyc_recheck,
+            keys::SETTLEMENT_TIMEOUT => self.settlement_timeout,
             keys::SYNTHETIC_PACKAGE_HASH=>Key::Hash(self.synth_package_hash.value()),
+            keys::MARKETPLACE_SETTINGS => Key::Hash(self.marketplace_settings.value()),
             keys::TOKEN_ID=>self.token_id.to_owned(),
             keys::START => self.start_time,
             keys::END => self.end_time,
             keys::NAME => self.name.clone(),
+            keys::UPGRADE => false,
             keys::SWAP_PRICE=> self.swap_price,
+            keys::MARKETPLACE_ACCOUNT => self.marketplace_account.map(Key::Account),
+            keys::MARKETPLACE_COMMISSION => self.marketplace_commission,
+            keys::BENEFICIARIES => self.beneficiaries.as_ref().map(|beneficiaries| {
+                beneficiaries
+                    .iter()
+                    .map(|(account, share)| (Key::Account(*account), *share))
+                    .collect::<BTreeMap<Key, u32>>()
+            }),
         }
     }
 
@@ -88,14 +121,40 @@ impl BaseAuctionArgs for AuctionArgBuilder {
         self.kyc_package_hash = hash.clone();
     }
 
+    fn set_kyc_mode(&mut self, mode: Option<u8>, level: Option<U256>) {
+        self.kyc_mode = mode;
+        self.kyc_level = level;
+    }
+
+    fn set_kyc_recheck(&mut self, recheck: Option<bool>) {
+        self.kyc_recheck = recheck;
+    }
+
+    fn set_settlement_timeout(&mut self, timeout: Option<u64>) {
+        self.settlement_timeout = timeout;
+    }
+
     fn set_synth_package_hash(&mut self, hash: &ContractPackageHash){
         self.synth_package_hash = hash.clone();
     }
 
+    fn set_marketplace_settings(&mut self, hash: &ContractPackageHash) {
+        self.marketplace_settings = *hash;
+    }
+
     fn set_token_id(&mut self, token_id: &String){
         self.token_id = token_id.clone();
     }
 
+    fn set_marketplace(&mut self, account: Option<AccountHash>, commission: Option<u32>) {
+        self.marketplace_account = account;
+        self.marketplace_commission = commission;
+    }
+
+    fn set_beneficiaries(&mut self, beneficiaries: Option<BTreeMap<AccountHash, u32>>) {
+        self.beneficiaries = beneficiaries;
+    }
+
     fn get_nft_commission(&self) -> u32 {
         self.nft_commission
     }
@@ -113,13 +172,21 @@ impl Default for AuctionArgBuilder {
             beneficiary_account: account.clone(),
             token_contract_hash: ContractPackageHash::new([0u8; 32]),
             kyc_package_hash: ContractPackageHash::new([0u8; 32]),
+            kyc_mode: None,
+            kyc_level: None,
+            kyc_recheck: None,
+            settlement_timeout: None,
             synth_package_hash: ContractPackageHash::new([0u8; 32]),
+            marketplace_settings: ContractPackageHash::new([0u8; 32]),
             token_id: "token_id".to_string(),
             start_time: now + 500,
             end_time: now + 3500,
             name: "test".to_string(),
             swap_price: U512::from(20000),
-            nft_commission: 100_u32
+            nft_commission: 100_u32,
+            marketplace_account: None,
+            marketplace_commission: None,
+            beneficiaries: None,
         }
     }
 }
@@ -45,7 +45,7 @@ impl SwapAuctionContract {
     }
 
     pub fn reject(&mut self, caller: &AccountHash, time: u64) {
-        self.contract.reject(caller, time)
+        self.contract.reject(caller, false, time)
     }
 
 }
@@ -0,0 +1,96 @@
+use casper_types::{Key, U512};
+use crate::auction::BaseAuctionArgs;
+use crate::dutch_args;
+use crate::dutch_auction::DutchAuctionContract;
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+#[should_panic = "User(1)"]
+fn reject_before_settlement_deadline() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_settlement_timeout(Some(1000));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, _, ali, _, dan) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.synthetic_bid(&market, &ali, U512::from(50000), now + 1000);
+    auction.finalize(&admin, now + 5000);
+    assert!(auction.contract.is_pending_settle());
+    auction.reject(&dan, now + 5500);
+}
+
+#[test]
+fn reject_overdue_settlement_to_next_bid() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_settlement_timeout(Some(1000));
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (admin, market, _, ali, bob, dan) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    auction.synthetic_bid(&market, &ali, U512::from(50000), now + 1500);
+    auction.finalize(&admin, now + 5000);
+    assert!(auction.contract.is_pending_settle());
+
+    // Anyone can reject once the deadline passed, the runner-up wins with their own bid
+    auction.reject_to_next_bid(&dan, now + 6000);
+    assert!(auction.contract.is_settled());
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(bob)), Some((U512::from(40000), false))));
+}
+
+#[test]
+fn fund_synthetic_winner() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (admin, market, _, ali, _, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.synthetic_bid(&market, &ali, U512::from(50000), now + 1000);
+    auction.finalize(&admin, now + 5000);
+    assert!(auction.contract.is_pending_settle());
+
+    auction.contract.fund_synthetic(&ali, U512::from(50000), now + 5500);
+    assert!(auction.contract.is_settled());
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(ali)), Some((U512::from(50000), false))));
+}
+
+#[test]
+fn convert_funded_bid_to_synthetic() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, market, _, _, bob, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    // Same amount, other funding - the bid keeps its place
+    auction.synthetic_bid(&market, &bob, U512::from(40000), now + 1500);
+    assert_eq!(auction.contract.get_current_winner(), (Some(Key::Account(bob)), Some((U512::from(40000), true))));
+}
+
+#[test]
+fn synth_bid_cancel_reopens_auction() {
+    let now = utils::get_now_u64();
+    let auction_args = dutch_args::AuctionArgBuilder::base(
+        now,
+        U512::from(30000),
+        U512::from(20000),
+        100
+    );
+    let mut auction = DutchAuctionContract::deploy(auction_args);
+    let (_, market, _, ali, _, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+
+    auction.synthetic_bid(&market, &ali, U512::from(30000), now + 1000);
+    assert!(auction.contract.is_pending_settle());
+
+    // The withdrawn bid puts the auction back on sale
+    auction.contract.cancel_synthetic_bid(&market, &ali, now + 1500);
+    assert!(auction.contract.is_live());
+    assert_eq!(auction.contract.get_current_winner(), (None, None));
+}
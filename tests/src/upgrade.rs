@@ -0,0 +1,118 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U512};
+use casper_private_auction_core::keys;
+use crate::auction::{AuctionContract, BaseAuctionArgs};
+use crate::english_args::AuctionArgBuilder;
+use crate::english_auction::EnglishAuctionContract;
+use crate::utils;
+
+#[test]
+fn upgrade_keeps_state() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    // Named key layout of the first release
+    auction_args.set_installer("legacy-auction-installer.wasm");
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, market, artist, _, bob, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    let (old_hash, package) = auction.contract.auction_contract;
+    assert!(!auction.contract.has_auction_key(keys::STATE_VERSION));
+    assert!(!auction.contract.has_auction_key(keys::COMMISSIONS));
+
+    auction.contract.upgrade("english-auction-installer.wasm".to_string());
+    assert_ne!(auction.contract.auction_contract.0, old_hash);
+    assert_eq!(auction.contract.auction_contract.1, package);
+    // The layout only changes once the admin migrates it
+    assert!(!auction.contract.has_auction_key(keys::STATE_VERSION));
+
+    auction.contract.migrate(&market, now + 500);
+    assert_eq!(auction.contract.get_state_version(), 1);
+    for name in [
+        keys::COMMISSIONS,
+        keys::BENEFICIARIES,
+        keys::RECIPIENT_PURSES,
+        keys::KYC_MODE,
+        keys::KYC_RECHECK,
+        keys::SETTLEMENT_STATUS,
+        keys::SETTLEMENT_TIMEOUT,
+        keys::PAUSED_AT,
+        keys::FACTORY,
+        keys::GATING_LIST,
+        keys::BIDDING_AGENTS,
+        keys::PROXY_BIDS,
+        keys::MINIMUM_BID_INCREMENTS,
+        keys::SELLER_CANCELLATION_PENALTY,
+    ] {
+        assert!(auction.contract.has_auction_key(name), "{} missing", name);
+    }
+    // Commissions are frozen from the token at migration
+    assert_eq!(auction.contract.get_commissions().get(&Key::Account(artist)), Some(&1000));
+
+    auction.bid(&bob, U512::from(40000), now + 1000);
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(bob)));
+}
+
+#[test]
+fn migrated_auction_settles() {
+    let now = utils::get_now_u64();
+    let mut auction_args = AuctionArgBuilder::default();
+    auction_args.set_start_time(now);
+    auction_args.set_installer("legacy-auction-installer.wasm");
+    let mut auction = EnglishAuctionContract::deploy(auction_args);
+    let (_, market, _, ali, bob, _) = auction.contract.accounts;
+    auction.contract.transfer_funds(&market, U512::from(100_000_000_000_000_u64));
+    // Stored under the account hash of bob, with a per-mille marketplace commission
+    auction.contract.full_bid(&bob, U512::from(40000), now + 1000);
+
+    auction.contract.upgrade("english-auction-installer.wasm".to_string());
+    auction.contract.migrate(&market, now + 1500);
+    assert_eq!(auction.contract.get_current_winner().0, Some(Key::Account(bob)));
+    assert_eq!(auction.contract.get_fees(&ali, now + 1500), (market, 250));
+
+    let (_, market_before, _, _, _, _) = auction.contract.get_balances();
+    auction.finalize(&ali, now + 5000);
+    assert!(auction.contract.is_settled());
+    // 2.5% of the winning bid
+    let (_, market_after, _, _, _, _) = auction.contract.get_balances();
+    assert_eq!(market_after - market_before, U512::from(1000));
+}
+
+#[test]
+#[should_panic = "User(1)"]
+fn migrate_not_admin() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let (_, _, _, _, bob, _) = auction.contract.accounts;
+
+    auction.contract.migrate(&bob, now + 500);
+}
+
+#[test]
+#[should_panic = "User(85)"]
+fn install_existing_name_without_upgrade() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let admin = auction.contract.accounts.0;
+
+    AuctionContract::deploy_auction(
+        "english-auction-installer.wasm".to_string(),
+        &mut auction.contract.builder,
+        &admin,
+        runtime_args! { keys::NAME => "test".to_string(), keys::UPGRADE => false },
+    );
+}
+
+#[test]
+#[should_panic = "User(86)"]
+fn upgrade_not_installed() {
+    let now = utils::get_now_u64();
+    let mut auction = EnglishAuctionContract::deploy_with_default_args(now);
+    let admin = auction.contract.accounts.0;
+
+    AuctionContract::deploy_auction(
+        "english-auction-installer.wasm".to_string(),
+        &mut auction.contract.builder,
+        &admin,
+        runtime_args! { keys::NAME => "other".to_string(), keys::UPGRADE => true },
+    );
+}